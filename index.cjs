@@ -284,9 +284,24 @@ if (!nativeBinding) {
 	throw new Error(`Failed to load native binding`);
 }
 
-const { Boundary, Sentence, WordMatch, Word } = nativeBinding;
+const { ContextRule, ConversationBuffer, ParseErrorCode, fingerprintSimilarity, FingerprintIndex, Protection, ProximityRule, RestrictionLevel, Boundary, SentenceSnapshot, Sentence, StreamScanner, TraceStepKind, AbandonReason, MatchKind, WordMatch, Word, WordList, WordPartKind } = nativeBinding;
 
+module.exports.ContextRule = ContextRule;
+module.exports.ConversationBuffer = ConversationBuffer;
+module.exports.ParseErrorCode = ParseErrorCode;
+module.exports.fingerprintSimilarity = fingerprintSimilarity;
+module.exports.FingerprintIndex = FingerprintIndex;
+module.exports.Protection = Protection;
+module.exports.ProximityRule = ProximityRule;
+module.exports.RestrictionLevel = RestrictionLevel;
 module.exports.Boundary = Boundary;
+module.exports.SentenceSnapshot = SentenceSnapshot;
 module.exports.Sentence = Sentence;
+module.exports.StreamScanner = StreamScanner;
+module.exports.TraceStepKind = TraceStepKind;
+module.exports.AbandonReason = AbandonReason;
+module.exports.MatchKind = MatchKind;
 module.exports.WordMatch = WordMatch;
 module.exports.Word = Word;
+module.exports.WordList = WordList;
+module.exports.WordPartKind = WordPartKind;
@@ -3,6 +3,464 @@
 
 /* auto-generated by NAPI-RS */
 
+/**
+ * Signals of the original text of a `Sentence`, used to detect spam, see
+ * `Sentence#metrics`.
+ */
+export interface SpamMetrics {
+	/** The amount of letters in the message. */
+	letters: number;
+	/** The amount of uppercase letters in the message. */
+	uppercase: number;
+	/** The ratio of uppercase letters over all the letters, from `0` to `1`. */
+	capsRatio: number;
+	/**
+	 * The longest amount of times a character was repeated in a row, not
+	 * counting whitespace.
+	 */
+	longestRepeat: number;
+	/**
+	 * The amount of times a character was repeated at least four times in a
+	 * row, such as `"!!!!"` or `"aaaaaa"`.
+	 */
+	floods: number;
+	/**
+	 * The amount of combining marks in the message, which are stacked to
+	 * write zalgo text.
+	 */
+	combiningMarks: number;
+	/**
+	 * The ratio of combining marks over the rest of the visible characters,
+	 * which is greater than `1` when every character has several marks. This
+	 * is the zalgo score of the message.
+	 */
+	zalgoDensity: number;
+	/** The largest amount of combining marks stacked on a single character. */
+	longestMarkStack: number;
+	/**
+	 * The amount of emoji in the message, including custom emoji, where
+	 * sequences such as flags or families are counted once.
+	 */
+	emoji: number;
+	/** Whether or not the message only has emoji and whitespace. */
+	emojiOnly: boolean;
+	/** The amount of user mentions, such as `<@123>`. */
+	mentions: number;
+	/** The amount of role mentions, such as `<@&123>`. */
+	roleMentions: number;
+	/** The amount of `@everyone` and `@here` mentions. */
+	massMentions: number;
+}
+/** The result of matching a `WordList` against a single message of a batch. */
+export interface BatchMatchResult {
+	/** Whether or not any of the words matched the message. */
+	matched: boolean;
+	/** The indexes of the words of the list that matched the message. */
+	words: Array<number>;
+	/**
+	 * The marked characters of the message as edits over the message, see
+	 * `Sentence#toEdits`.
+	 */
+	edits: Array<SentenceEdit>;
+}
+/** Words looked for in the tokens around a match of a `ContextRule`. */
+export interface ContextCondition {
+	/**
+	 * The patterns of the words, any of them fulfills the condition. The
+	 * words of a phrase must be found in consecutive tokens.
+	 */
+	words: Array<string>;
+	/**
+	 * The amount of tokens next to the match that are searched, defaults to
+	 * `1`.
+	 */
+	within?: number;
+}
+export interface ContextRuleOptions {
+	/** The words that must follow the match. */
+	followedBy?: ContextCondition;
+	/** The words that must precede the match. */
+	precededBy?: ContextCondition;
+	/** The words that must not follow the match. */
+	notFollowedBy?: ContextCondition;
+	/** The words that must not precede the match. */
+	notPrecededBy?: ContextCondition;
+}
+/**
+ * A `Word` that only matches depending on the words around it, such as
+ * `"kill"` followed by `"yourself"`, but not by `"the process"`.
+ *
+ * The surrounding words are looked for in the tokens of the sentence, the
+ * runs of word characters next to the tokens of the match.
+ *
+ * @example
+ *
+ * ```ts
+ * const rule = new ContextRule(new Word("kill"), { followedBy: { words: ["yourself", "urself"], within: 2 } });
+ * rule.matches(new Sentence("go kill yourself"));
+ * // ⇒ true
+ * rule.matches(new Sentence("kill the process"));
+ * // ⇒ false
+ * ```
+ */
+export class ContextRule {
+	/**
+	 * @param word - The word to match.
+	 * @param options - The words that must or must not surround the matches.
+	 *
+	 * @remarks
+	 *
+	 * If a pattern cannot be parsed, the thrown error has a `word` property
+	 * with the index of the offending pattern within its condition, like the
+	 * ones thrown by `WordList`.
+	 */
+	constructor(word: Word, options?: ContextRuleOptions | undefined | null);
+	matches(sentence: Sentence): boolean;
+	/**
+	 * Matches the rule against a `Sentence` like `matches`, returning the
+	 * position of every accepted match within the sentence's contents.
+	 */
+	find(sentence: Sentence): Array<WordMatch>;
+}
+export interface ConversationBufferOptions {
+	/**
+	 * The amount of most recent messages kept per conversation, defaults to
+	 * `5`.
+	 */
+	size?: number;
+	/**
+	 * The amount of conversations kept at once, defaults to `1000`. When a
+	 * message is pushed into a new conversation and the buffer is full, the
+	 * conversation that received a message the longest time ago is dropped.
+	 */
+	maxConversations?: number;
+}
+/** The part of a `ConversationMatch` within one message. */
+export interface MessageRange {
+	/** The ID of the message. */
+	id: string;
+	/** The UTF-16 offset of the first matched character within the message. */
+	start: number;
+	/** The UTF-16 offset after the last matched character within the message. */
+	end: number;
+}
+/** A match found by a `ConversationBuffer`, which may span several messages. */
+export interface ConversationMatch {
+	/** The index of the word of the list that matched. */
+	word: number;
+	/**
+	 * The matched range of every message the match spans, from the oldest to
+	 * the newest message.
+	 */
+	messages: Array<MessageRange>;
+}
+/**
+ * A buffer of the most recent messages of every conversation (such as a
+ * channel or a user), matching a `WordList` across them to catch words
+ * split into several messages, such as `"b"`, `"a"`, `"r"`.
+ *
+ * @example
+ *
+ * ```ts
+ * const buffer = new ConversationBuffer(new WordList(["bar"]), { size: 3 });
+ * buffer.push("channel", "1", "b");
+ * buffer.push("channel", "2", "a");
+ * buffer.push("channel", "3", "r!");
+ * // ⇒ [{ word: 0, messages: [{ id: "1", start: 0, end: 1 }, { id: "2", start: 0, end: 1 }, { id: "3", start: 0, end: 1 }] }]
+ * ```
+ */
+export class ConversationBuffer {
+	constructor(list: WordList, options?: ConversationBufferOptions | undefined | null);
+	/**
+	 * Pushes a message into a conversation, dropping its oldest message if it
+	 * is full, and matches the word list against the messages it holds. If
+	 * the conversation is new and the buffer is full, the least recently used
+	 * conversation is dropped.
+	 *
+	 * @param key - The key of the conversation, such as a channel or user ID.
+	 * @param id - The ID of the message.
+	 * @param message - The content of the message.
+	 *
+	 * @returns The matches that end within the pushed message, as the others
+	 * were returned by previous calls.
+	 */
+	push(key: string, id: string, message: string): Array<ConversationMatch>;
+	/**
+	 * Removes the messages of a conversation, or of all of them.
+	 *
+	 * @param key - The key of the conversation, if omitted, all the
+	 * conversations are cleared.
+	 */
+	clear(key?: string | undefined | null): void;
+}
+/**
+ * The code of a `ParseError`, exposed as the `code` property of the errors
+ * thrown when a pattern cannot be parsed.
+ */
+export enum ParseErrorCode {
+	/** A character group (`"[abc]"`) is never closed. */
+	UnterminatedGroup = 'UnterminatedGroup',
+	/** The escape character is at the end of the pattern. */
+	TrailingEscape = 'TrailingEscape',
+	/** The pattern has no parts. */
+	EmptyPattern = 'EmptyPattern',
+	/** The pattern only has wildcards that unbind its boundaries. */
+	WildcardOnly = 'WildcardOnly',
+	/** A part given to `Word.fromParts` is not valid. */
+	InvalidPart = 'InvalidPart',
+	/** A flag of the pattern (such as the `"c"` in `"(?c)"`) is not known. */
+	UnknownFlag = 'UnknownFlag',
+	/** The flags of the pattern (`"(?c)"`) are never closed. */
+	UnterminatedFlags = 'UnterminatedFlags',
+	/** The flags of the pattern (`"(?)"`) are empty. */
+	EmptyFlags = 'EmptyFlags',
+	/** A whitespace character is escaped (`"a\ b"`) outside a group. */
+	EscapedWhitespace = 'EscapedWhitespace'
+}
+/**
+ * Computes how similar two fingerprints created by `Sentence#fingerprint`
+ * are, from `0` to `1`, where `1` means that they are equal.
+ *
+ * @param a - The first fingerprint.
+ * @param b - The second fingerprint.
+ *
+ * @example
+ *
+ * ```ts
+ * const a = new Sentence("join my server at example").fingerprint();
+ * const b = new Sentence("j0in my s e r v e r at example!!").fingerprint();
+ * fingerprintSimilarity(a, b);
+ * // ⇒ 0.859375
+ * ```
+ */
+export export declare function fingerprintSimilarity(a: string, b: string): number;
+export interface FingerprintIndexOptions {
+	/**
+	 * The amount of fingerprints kept, the oldest ones are removed once it is
+	 * reached, defaults to `1000`.
+	 */
+	capacity?: number;
+	/**
+	 * The minimum similarity of the fingerprints returned by `find`, from `0`
+	 * to `1`, defaults to `0.85`.
+	 */
+	threshold?: number;
+}
+/**
+ * A fingerprint of a `FingerprintIndex` that is similar to the one looked
+ * up.
+ */
+export interface FingerprintMatch {
+	/** The ID the fingerprint was inserted with. */
+	id: string;
+	/** The fingerprint. */
+	fingerprint: string;
+	/**
+	 * The similarity between the fingerprint and the one looked up, from `0`
+	 * to `1`.
+	 */
+	similarity: number;
+}
+/**
+ * A bounded in-memory index of the most recent fingerprints, used to find
+ * whether a similar message was seen recently, such as during raids.
+ *
+ * @example
+ *
+ * ```ts
+ * const index = new FingerprintIndex({ capacity: 500 });
+ * index.insert("message-1", new Sentence("join my server at example").fingerprint());
+ * index.find(new Sentence("j0in my s e r v e r at example!!").fingerprint());
+ * // ⇒ [{ id: "message-1", fingerprint: "...", similarity: 0.859375 }]
+ * ```
+ */
+export class FingerprintIndex {
+	constructor(options?: FingerprintIndexOptions | undefined | null);
+	/**
+	 * Inserts a fingerprint, removing the oldest one if the index is full.
+	 *
+	 * @param id - An ID to identify the fingerprint, such as a message ID.
+	 * @param fingerprint - A fingerprint created by `Sentence#fingerprint`.
+	 */
+	insert(id: string, fingerprint: string): void;
+	/**
+	 * Finds the fingerprints of the index that are similar to a fingerprint,
+	 * sorted from the most to the least similar.
+	 *
+	 * @param fingerprint - A fingerprint created by `Sentence#fingerprint`.
+	 * @param threshold - The minimum similarity of the returned fingerprints,
+	 * defaults to the threshold of the index.
+	 */
+	find(fingerprint: string, threshold?: number | undefined | null): Array<FingerprintMatch>;
+	/** Removes all the fingerprints of the index. */
+	clear(): void;
+	get length(): number;
+}
+/**
+ * The kind of a range of a `Sentence` that is never matched, see
+ * `SentenceOptions.protect`.
+ */
+export enum Protection {
+	/** A URL starting with `http://` or `https://`. */
+	Url = 0,
+	/** A user mention, such as `<@123>` or `<@!123>`. */
+	Mention = 1,
+	/** A channel reference, such as `<#123>`. */
+	Channel = 2,
+	/** A role mention, such as `<@&123>`. */
+	Role = 3,
+	/** A custom emoji, such as `<:kekw:123>` or `<a:kekw:123>`. */
+	Emoji = 4,
+	/** A range given in `SentenceOptions.ranges`. */
+	Custom = 5,
+	/** A code span or block, such as `` `code` `` or a fenced block. */
+	Code = 6,
+	/**
+	 * A quote, either a line starting with `"> "`, or the rest of the message
+	 * after a line starting with `">>> "`.
+	 */
+	Quote = 7
+}
+/** A range of characters of a string. */
+export interface TextRange {
+	/**
+	 * The UTF-16 offset of the first character of the range, as used by
+	 * JavaScript strings.
+	 */
+	start: number;
+	/**
+	 * The UTF-16 offset after the last character of the range, as used by
+	 * JavaScript strings.
+	 */
+	end: number;
+}
+/**
+ * A range of a `Sentence` that is never matched, see
+ * `Sentence#protectedRanges`.
+ */
+export interface ProtectedRange {
+	/**
+	 * The UTF-16 offset of the first character of the range in the original
+	 * sentence.
+	 */
+	start: number;
+	/**
+	 * The UTF-16 offset after the last character of the range in the original
+	 * sentence.
+	 */
+	end: number;
+	/** The kind of the range. */
+	kind: Protection;
+}
+/** A match of a `ProximityRule`, spanning the matches of all its words. */
+export interface ProximityMatch {
+	/**
+	 * The index of the first character of the span within the sentence's
+	 * contents.
+	 */
+	start: number;
+	/**
+	 * The index after the last character of the span within the sentence's
+	 * contents.
+	 */
+	end: number;
+	/**
+	 * The match of every word of the rule, in the same order as the
+	 * patterns.
+	 */
+	matches: Array<WordMatch>;
+}
+/**
+ * A rule that matches when all its words are found close to each other, in
+ * any order, such as a target word within a few tokens of a threat word.
+ *
+ * The distance between two matches is the difference between the indexes of
+ * their tokens, so adjacent words are at a distance of `1`.
+ *
+ * @example
+ *
+ * ```ts
+ * const rule = new ProximityRule(["kill", "**you**"], 3);
+ * rule.find(new Sentence("i will find you and kill you"));
+ * // ⇒ [{ start: 12, end: 24, matches: [{ start: 20, end: 24, ... }, { start: 12, end: 15, ... }] }]
+ * ```
+ */
+export class ProximityRule {
+	/**
+	 * @param patterns - The patterns of the words, at least two.
+	 * @param distance - The maximum distance in tokens between the matches of
+	 * the words.
+	 *
+	 * @remarks
+	 *
+	 * If a pattern cannot be parsed, the thrown error has a `word` property
+	 * with the index of the offending pattern, like the ones thrown by
+	 * `WordList`.
+	 */
+	constructor(patterns: Array<string>, distance: number);
+	matches(sentence: Sentence): boolean;
+	/**
+	 * Matches the rule against a `Sentence` like `matches`, returning the span
+	 * of every match within the sentence's contents.
+	 */
+	find(sentence: Sentence): Array<ProximityMatch>;
+}
+/**
+ * The restriction levels defined by UTS #39, from the most to the least
+ * restrictive. A token conforms to a level if it conforms to any of the
+ * levels before it.
+ */
+export enum RestrictionLevel {
+	/** All the characters are ASCII. */
+	AsciiOnly = 0,
+	/** All the characters belong to a single script. */
+	SingleScript = 1,
+	/**
+	 * The characters are Latin mixed with Han, Hiragana, and Katakana, with
+	 * Han and Bopomofo, or with Han and Hangul.
+	 */
+	HighlyRestrictive = 2,
+	/**
+	 * The characters are Latin mixed with a single other recommended script,
+	 * other than Cyrillic or Greek.
+	 */
+	ModeratelyRestrictive = 3,
+	/** The characters mix scripts arbitrarily, such as Latin with Cyrillic. */
+	MinimallyRestrictive = 4,
+	/**
+	 * Some of the characters are not allowed in identifiers, such as
+	 * mathematical alphanumeric symbols like `"𝕙"`.
+	 */
+	Unrestricted = 5
+}
+/** The scripts of a token of a `Sentence`, see `Sentence#scripts`. */
+export interface TokenScripts {
+	/** The token, as written in the original sentence. */
+	text: string;
+	/**
+	 * The UTF-16 offset of the first character of the token in the original
+	 * sentence.
+	 */
+	start: number;
+	/**
+	 * The UTF-16 offset after the last character of the token in the original
+	 * sentence.
+	 */
+	end: number;
+	/**
+	 * The names of the scripts of the characters of the token, in order of
+	 * appearance, not including the characters shared by several scripts,
+	 * such as digits.
+	 */
+	scripts: Array<string>;
+	/**
+	 * Whether or not the token mixes scripts, as defined by UTS #39: a token
+	 * mixing Han, Hiragana, and Katakana is still single-script.
+	 */
+	mixed: boolean;
+	/** The most restrictive level the token conforms to. */
+	restrictionLevel: RestrictionLevel;
+}
 export enum Boundary {
 	/** The start of a new word. */
 	Start = 0,
@@ -26,6 +484,131 @@ export interface ToCensoredStringOptions {
 	/** The original sentence before it was sanitized and lowercased. */
 	original: string;
 }
+export interface SentenceOptions {
+	/**
+	 * The kinds of ranges detected in the sentence that are never matched,
+	 * such as URLs or mentions, defaults to none.
+	 */
+	protect?: Array<Protection>;
+	/** Custom ranges of the sentence that are never matched. */
+	ranges?: Array<TextRange>;
+	/**
+	 * Whether or not Discord formatting markers (`*`, `_`, `~`, and `|`)
+	 * within words are ignored, so `"f**u**ck"` reads as `"fuck"`, defaults
+	 * to `false`.
+	 *
+	 * The ignored markers are censored along with the word they are in.
+	 */
+	markdown?: boolean;
+}
+export interface AcrosticOptions {
+	/**
+	 * Whether or not the last alphanumeric character of every line is also
+	 * read, as a second word after the first characters, defaults to `false`.
+	 */
+	last?: boolean;
+}
+export interface ToEditsOptions {
+	/** The character to use to censor the marked characters, defaults to `'*'`. */
+	character?: string;
+}
+/**
+ * An edit to apply to the original sentence, replacing the characters
+ * between `start` and `end` with `replacement`.
+ */
+export interface SentenceEdit {
+	/**
+	 * The UTF-16 offset of the first replaced character in the original
+	 * sentence, as used by JavaScript strings.
+	 */
+	start: number;
+	/**
+	 * The UTF-16 offset after the last replaced character in the original
+	 * sentence, as used by JavaScript strings.
+	 */
+	end: number;
+	/**
+	 * The UTF-8 byte offset of the first replaced character in the original
+	 * sentence.
+	 */
+	byteStart: number;
+	/**
+	 * The UTF-8 byte offset after the last replaced character in the original
+	 * sentence.
+	 */
+	byteEnd: number;
+	/** The text that replaces the characters within the range. */
+	replacement: string;
+}
+/** How much of a `Sentence` was marked, see `Sentence#statistics`. */
+export interface SentenceStatistics {
+	/**
+	 * The amount of word characters of the sentence, not including the
+	 * protected ranges.
+	 */
+	characters: number;
+	/** The amount of word characters that were marked. */
+	markedCharacters: number;
+	/** The ratio of word characters that were marked, from `0` to `1`. */
+	markedRatio: number;
+	/** The amount of tokens of the sentence, the runs of word characters. */
+	tokens: number;
+	/** The amount of tokens with at least one marked character. */
+	markedTokens: number;
+	/**
+	 * The distinct words that marked characters of the sentence, in the
+	 * order they first matched.
+	 */
+	patterns: Array<MatchedPattern>;
+}
+/**
+ * A word that marked characters of a `Sentence`, see
+ * `SentenceStatistics.patterns`. Words with the same pattern but different
+ * options are reported separately.
+ */
+export interface MatchedPattern {
+	/** The pattern of the word, including its flags. */
+	pattern: string;
+	/** The maximum distance of the word, see `WordOptions.maxDistance`. */
+	maxDistance: number;
+	/**
+	 * Whether or not the word matches phonetically, see
+	 * `WordOptions.phonetic`.
+	 */
+	phonetic: boolean;
+	/** Whether or not the word matches backwards, see `WordOptions.reversed`. */
+	reversed: boolean;
+}
+/** Where a character of a `Sentence` comes from, and how it was normalized. */
+export interface CharacterProvenance {
+	/** The normalized character, as read by the matchers. */
+	character: string;
+	/**
+	 * The character of the original sentence that produced the normalized
+	 * character.
+	 */
+	original: string;
+	/** The UTF-16 offset of the original character in the original sentence. */
+	start: number;
+	/**
+	 * The UTF-16 offset after the original character in the original
+	 * sentence.
+	 */
+	end: number;
+	/**
+	 * Whether or not the original character is a confusable (such as `"а"`,
+	 * the Cyrillic letter) that was replaced with its base characters.
+	 */
+	confusable: boolean;
+	/** Whether or not the character was lowercased. */
+	caseFolded: boolean;
+}
+/**
+ * A copy of the marks of a `Sentence`, created with `Sentence#snapshot` and
+ * applied back with `Sentence#restore`.
+ */
+export class SentenceSnapshot {
+}
 /**
  * A struct that represents a sentence. The sentence is split into spans that
  * are checked by the `Word` class. The spans are updated when a word is
@@ -71,7 +654,43 @@ export class Sentence {
 	 * or infixes of other words.
 	 */
 	boundaries: Array<Boundary>;
-	constructor(sentence: string);
+	/**
+	 * @param sentence - The sentence to match words against.
+	 * @param options - The options of the sentence.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const sentence = new Sentence("<@123> check https://example.com/ass", {
+	 * 	protect: [Protection.Mention, Protection.Url],
+	 * });
+	 * new Word("**ass**").matches(sentence);
+	 * // ⇒ false
+	 * ```
+	 */
+	constructor(sentence: string, options?: SentenceOptions | undefined | null);
+	/**
+	 * Creates a virtual sentence from the first alphanumeric character of
+	 * every line of a text, so words written down the start of the lines are
+	 * matched. The marks are mapped back to the original text, so `toEdits`
+	 * and `toCensoredString` only censor the characters that were read.
+	 *
+	 * @param sentence - The text to read the lines of.
+	 * @param options - The options of the acrostic.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const sentence = Sentence.acrostic("Big news
+	All of you;
+	Read this");;
+	 * new Word("bar").matches(sentence);
+	 * // ⇒ true
+	 * sentence.toEdits();
+	 * // ⇒ [{ start: 0, end: 1, ... }, { start: 9, end: 10, ... }, { start: 20, end: 21, ... }]
+	 * ```
+	 */
+	static acrostic(sentence: string, options?: AcrosticOptions | undefined | null): Sentence;
 	/**
 	 * Gets the length of the array. This is a number one higher than the
 	 * highest index in the array.
@@ -110,14 +729,388 @@ export class Sentence {
 	 *
 	 * @remarks
 	 *
-	 * The original sentence must have the same length as the sentence, or an
-	 * error will be thrown.
+	 * The original sentence must have as many characters as the text the
+	 * sentence was read from, or an error will be thrown. Like `toEdits`, the
+	 * marks are mapped back to the characters they were read from, so
+	 * formatting markers within a censored word are censored as well.
 	 */
 	toCensoredString(options: ToCensoredStringOptions): string;
+	/**
+	 * Returns the marked characters of the sentence as a list of edits over
+	 * the original sentence, so they can be applied by the caller.
+	 *
+	 * @param options - The options to use when censoring the marked
+	 * characters.
+	 *
+	 * @returns The edits, ordered by their position and without overlaps.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const sentence = new Sentence("Pepe ate a banana");
+	 * const word = new Word("Pepe");
+	 *
+	 * word.matches(sentence);
+	 * sentence.toEdits({ character: "X" });
+	 * // ⇒ [{ start: 0, end: 4, byteStart: 0, byteEnd: 4, replacement: "XXXX" }]
+	 * ```
+	 */
+	toEdits(options?: ToEditsOptions | undefined | null): Array<SentenceEdit>;
+	/**
+	 * Returns where every character of the sentence comes from, and how it
+	 * was normalized, in the same order as the characters of `toString`.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const sentence = new Sentence("Pаy");
+	 * sentence.provenance();
+	 * // ⇒ [
+	 * // 	{ character: "p", original: "P", start: 0, end: 1, confusable: false, caseFolded: true },
+	 * // 	{ character: "a", original: "а", start: 1, end: 2, confusable: true, caseFolded: false },
+	 * // 	{ character: "y", original: "y", start: 2, end: 3, confusable: false, caseFolded: false },
+	 * // ]
+	 * ```
+	 */
+	provenance(): Array<CharacterProvenance>;
+	/**
+	 * Returns the scripts of every token of the original sentence, flagging
+	 * the tokens that mix scripts, such as a Cyrillic `"р"` within `"paypal"`.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const sentence = new Sentence("Pay with рaypal");
+	 * sentence.scripts().filter((token) => token.mixed);
+	 * // ⇒ [{ text: "рaypal", start: 9, end: 15, scripts: ["Cyrillic", "Latin"], mixed: true, restrictionLevel: RestrictionLevel.MinimallyRestrictive }]
+	 * ```
+	 */
+	scripts(): Array<TokenScripts>;
+	/**
+	 * The spam signals of the original sentence, such as its ratio of
+	 * uppercase letters, or its amount of mentions.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const sentence = new Sentence("HELLO <@123> <@456> 🎉🎉🎉🎉");
+	 * sentence.metrics;
+	 * // ⇒ { letters: 5, uppercase: 5, capsRatio: 1, longestRepeat: 4, floods: 1, mentions: 2, ... }
+	 * ```
+	 *
+	 * @remarks
+	 *
+	 * The metrics are computed from the original sentence the first time they
+	 * are read, so matching words does not pay for them.
+	 */
+	get metrics(): SpamMetrics;
+	/**
+	 * Computes the fingerprint of the sentence, a 64-bit SimHash of its
+	 * normalized contents as 16 hexadecimal digits. Sentences that differ in
+	 * confusables, spacing, or punctuation have similar fingerprints, see
+	 * `fingerprintSimilarity`.
+	 *
+	 * @remarks
+	 *
+	 * The fingerprint does not depend on the marks of the sentence.
+	 */
+	fingerprint(): string;
+	/**
+	 * Saves the marks of the sentence, so they can be restored later with
+	 * `restore`.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const sentence = new Sentence("Pepe ate a banana");
+	 * const snapshot = sentence.snapshot();
+	 *
+	 * new Word("Pepe").matches(sentence);
+	 * sentence.restore(snapshot);
+	 * // "Pepe" is no longer marked
+	 * ```
+	 */
+	snapshot(): SentenceSnapshot;
+	/**
+	 * Restores the marks of the sentence to the state they had when
+	 * `snapshot` was called.
+	 *
+	 * @param snapshot - A snapshot created by this sentence.
+	 *
+	 * @remarks
+	 *
+	 * The snapshot must have been created by the same sentence, or by one with
+	 * the same contents and protected ranges, or an error will be thrown.
+	 */
+	restore(snapshot: SentenceSnapshot): void;
+	/**
+	 * Removes all the marks of the sentence, restoring it to the state it had
+	 * when it was created.
+	 */
+	resetMarks(): void;
+	/**
+	 * Counts the marked characters and tokens of the sentence, as well as the
+	 * patterns that marked them, for thresholds such as censoring the whole
+	 * message when most of it was marked.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const sentence = new Sentence("Pepe ate a banana");
+	 * new WordList(["pepe", "banana"]).matches(sentence);
+	 * sentence.statistics();
+	 * // ⇒ { characters: 14, markedCharacters: 10, markedRatio: 0.7142857142857143, tokens: 4, markedTokens: 2, patterns: [{ pattern: "pepe", ... }, { pattern: "banana", ... }] }
+	 * ```
+	 */
+	statistics(): SentenceStatistics;
+	/**
+	 * Returns the ranges of the original sentence that are never matched,
+	 * sorted by their position.
+	 */
+	protectedRanges(): Array<ProtectedRange>;
+}
+export interface StreamScannerOptions {
+	/**
+	 * The amount of characters of already scanned text that is kept to detect
+	 * matches that span across chunks, defaults to `64`.
+	 */
+	overlap?: number;
+}
+/** A match found by a `StreamScanner`. */
+export interface StreamMatch {
+	/** The index of the word of the list that matched. */
+	word: number;
+	/** The UTF-16 offset of the first matched character within the stream. */
+	start: number;
+	/** The UTF-16 offset after the last matched character within the stream. */
+	end: number;
+}
+/**
+ * A scanner that matches a `WordList` against text that is received in
+ * chunks, without building a `Sentence` for the whole text.
+ *
+ * Chunks are only scanned up to their last non-word character, the trailing
+ * word is kept until the next chunk (or `finish`) tells where it ends. A
+ * small window of already scanned text is also kept, so words split across
+ * chunks by non-word characters (such as `"b ar"`) are still matched.
+ *
+ * A trailing word is kept for at most as many characters as the longest
+ * pattern of the list, longer words are scanned as they arrive, so a stream
+ * without non-word characters does not grow the scanner without bound.
+ */
+export class StreamScanner {
+	constructor(list: WordList, options?: StreamScannerOptions | undefined | null);
+	/**
+	 * Pushes a chunk of text into the scanner, returning the matches that
+	 * could be completed with it.
+	 *
+	 * @param chunk - The next chunk of text of the stream.
+	 *
+	 * @returns The matches found, with their offsets relative to the start of
+	 * the stream.
+	 */
+	push(chunk: string): Array<StreamMatch>;
+	/**
+	 * Scans the remaining text of the stream, returning the last matches.
+	 * The scanner can be reused for a new stream afterwards.
+	 *
+	 * @returns The matches found, with their offsets relative to the start of
+	 * the stream.
+	 */
+	finish(): Array<StreamMatch>;
+}
+/** The outcome of reading a character while matching a candidate. */
+export enum TraceStepKind {
+	/**
+	 * The character matched the current part of the word, moving to the next
+	 * one.
+	 */
+	Matched = 0,
+	/**
+	 * The character did not match the current part of the word, but matched
+	 * the previous one, such as the second `"a"` in `"baar"` for `"bar"`.
+	 */
+	Repeated = 1,
+	/** The character is a non-word character, which is skipped. */
+	Skipped = 2,
+	/**
+	 * The character matched neither the current nor the previous part of the
+	 * word, abandoning the candidate unless it already matched.
+	 */
+	Mismatched = 3
+}
+/** The reason why a candidate was abandoned. */
+export enum AbandonReason {
+	/**
+	 * A character matched neither the current nor the previous part of the
+	 * word.
+	 */
+	Mismatch = 0,
+	/** The span ended before the word could be matched. */
+	EndOfSpan = 1,
+	/** The candidate matched, but it overlaps a previous match. */
+	AlreadyMarked = 2
+}
+/** A character read while matching a candidate. */
+export interface TraceStep {
+	/** The index of the character within the normalized text. */
+	index: number;
+	/** The character that was read. */
+	character: string;
+	/** The outcome of reading the character. */
+	kind: TraceStepKind;
+	/** The index of the part of the word that matched the character, if any. */
+	part?: number;
+	/**
+	 * The part of the word that matched the character, as written in the
+	 * pattern, if any.
+	 */
+	pattern?: string;
+}
+/**
+ * A position of the normalized text where the first part of the word
+ * matched, from which the rest of the word was tried.
+ */
+export interface TraceCandidate {
+	/**
+	 * The index of the character where the candidate started. Words that are
+	 * only bound to the right (such as `"**bar"`) are read backwards, starting
+	 * from their last character.
+	 */
+	start: number;
+	/** The index of the first matched character, if the candidate matched. */
+	matchStart?: number;
+	/** The index after the last matched character, if the candidate matched. */
+	matchEnd?: number;
+	/**
+	 * The amount of edits between the matched characters and the word, if
+	 * the candidate matched. Candidates tried with edits start at the first
+	 * character of a word of the sentence, and have no steps.
+	 */
+	distance?: number;
+	/** How the candidate was matched, if it matched. */
+	kind?: MatchKind;
+	/** Whether or not the candidate matched and was marked. */
+	matched: boolean;
+	/** The reason why the candidate was abandoned, if it did not match. */
+	reason?: AbandonReason;
+	/** The characters read while matching the candidate. */
+	steps: Array<TraceStep>;
+}
+/** A span of the sentence the word was matched against. */
+export interface TraceSpan {
+	/** The index of the first character of the span. */
+	start: number;
+	/** The index after the last character of the span. */
+	end: number;
+	/**
+	 * Whether or not the span was skipped because it is shorter than the
+	 * word.
+	 */
+	skipped: boolean;
+	/** The candidates that were tried within the span. */
+	candidates: Array<TraceCandidate>;
+}
+/** A trace of how a word was matched against a text, see `Word#explain`. */
+export interface MatchTrace {
+	/** The normalized text the word was matched against. */
+	normalized: string;
+	/** The word as it was parsed, see `Word#toString`. */
+	pattern: string;
+	/** Whether or not the word matched the text. */
+	matched: boolean;
+	/** The spans of the sentence that were tried. */
+	spans: Array<TraceSpan>;
+}
+/** How a `WordMatch` was found. */
+export enum MatchKind {
+	/** The characters matched the pattern of the word. */
+	Literal = 0,
+	/**
+	 * The characters matched the pattern of the word with some edits, see
+	 * `WordOptions.maxDistance`.
+	 */
+	Fuzzy = 1,
+	/** The characters sound like the word, see `WordOptions.phonetic`. */
+	Phonetic = 2,
+	/**
+	 * The characters matched the pattern of the word when read backwards,
+	 * see `WordOptions.reversed`.
+	 */
+	Reversed = 3
 }
 export class WordMatch {
 	get start(): number;
 	get end(): number;
+	/**
+	 * The amount of edits between the matched characters and the word, `0`
+	 * for exact matches, see `WordOptions.maxDistance`.
+	 */
+	get distance(): number;
+	/** How the match was found. */
+	get kind(): MatchKind;
+	/**
+	 * Whether or not any of the matched characters was replaced from a
+	 * confusable, such as a Cyrillic `"а"` in place of a Latin `"a"`, see
+	 * `Sentence#provenance`.
+	 */
+	get confusable(): boolean;
+}
+export interface WordOptions {
+	/**
+	 * The maximum amount of substitutions, insertions, deletions, or swaps of
+	 * adjacent characters allowed within a word of the sentence, defaults to
+	 * `0`.
+	 *
+	 * Words are allowed at most one edit for every three characters, so words
+	 * shorter than three characters are never matched with edits.
+	 */
+	maxDistance?: number;
+	/**
+	 * Whether or not the words of the sentence that sound like the word are
+	 * matched as well, comparing their English phonetic keys, defaults to
+	 * `false`.
+	 *
+	 * Phonetic keys keep the consonants and the vowels of a word, reading
+	 * `"e"` and `"i"` alike, so `"phuk"` sounds like `"fuck"`, but `"fake"`
+	 * does not.
+	 */
+	phonetic?: boolean;
+	/**
+	 * Whether or not the words of the sentence written backwards (such as
+	 * `"kcuf"`) are matched as well, defaults to `false`.
+	 *
+	 * This also catches words displayed backwards with right-to-left
+	 * override characters, as they are stored in reverse.
+	 */
+	reversed?: boolean;
+}
+export interface FromPartsOptions {
+	/**
+	 * Whether or not the word must match the left boundary of the sentence,
+	 * defaults to `true`.
+	 */
+	boundLeft?: boolean;
+	/**
+	 * Whether or not the word must match the right boundary of the sentence,
+	 * defaults to `true`.
+	 */
+	boundRight?: boolean;
+	/**
+	 * The maximum amount of edits allowed within a word of the sentence,
+	 * defaults to `0`, see `WordOptions.maxDistance`.
+	 */
+	maxDistance?: number;
+	/**
+	 * Whether or not the words that sound like the word are matched as well,
+	 * defaults to `false`, see `WordOptions.phonetic`.
+	 */
+	phonetic?: boolean;
+	/**
+	 * Whether or not the words written backwards are matched as well,
+	 * defaults to `false`, see `WordOptions.reversed`.
+	 */
+	reversed?: boolean;
 }
 /**
  * The `Word` struct represents a word that can be matched against a
@@ -135,8 +1128,190 @@ export class Word {
 	boundLeft: boolean;
 	/** If `true`, the word must match the right boundary of the sentence. */
 	boundRight: boolean;
-	constructor(word: string);
+	/**
+	 * The maximum amount of edits allowed within a word of the sentence. If
+	 * greater than `0`, the words of the sentence that do not match exactly
+	 * are compared with the word allowing up to this many edits.
+	 */
+	maxDistance: number;
+	/**
+	 * If `true`, the words of the sentence that do not match exactly are
+	 * compared with the word by their phonetic keys.
+	 */
+	phonetic: boolean;
+	/**
+	 * If `true`, the words of the sentence that do not match exactly are
+	 * read backwards and matched against the word.
+	 */
+	reversed: boolean;
+	/**
+	 * If `true`, the word is matched against the original case of the
+	 * sentence, set with the `"(?c)"` pattern flag.
+	 */
+	caseSensitive: boolean;
+	/**
+	 * If `true`, the word is matched against the sentence without replacing
+	 * its confusables, set with the `"(?l)"` pattern flag.
+	 */
+	literal: boolean;
+	/**
+	 * @param word - The pattern of the word.
+	 * @param options - The options of the word.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const word = new Word("fuck", { maxDistance: 1 });
+	 * word.find(new Sentence("what the fcuk"));
+	 * // ⇒ [{ start: 9, end: 13, distance: 1, kind: MatchKind.Fuzzy }]
+	 * ```
+	 *
+	 * @remarks
+	 *
+	 * If the pattern cannot be parsed, the thrown error has a `code` property
+	 * with the `ParseErrorCode` of the error, and an `index` property with the
+	 * UTF-16 offset of the offending character within the pattern.
+	 */
+	constructor(word: string, options?: WordOptions | undefined | null);
+	/**
+	 * Builds a `Word` from a list of parts, without writing its pattern.
+	 *
+	 * @param parts - The parts of the word.
+	 * @param options - The boundaries of the word, both default to `true`.
+	 *
+	 * @remarks
+	 *
+	 * Like in patterns, leading and trailing `anyWildcard` parts unbind the
+	 * respective boundary of the word. The `index` of the errors thrown by
+	 * this method is the index of the offending part.
+	 */
+	static fromParts(parts: Array<WordPartObject>, options?: FromPartsOptions | undefined | null): Word;
+	/**
+	 * The parts of the word, not including the any wildcards that unbind its
+	 * boundaries, see `boundLeft` and `boundRight`.
+	 *
+	 * @remarks
+	 *
+	 * Reading the parts of a phrase pattern throws an error, as a single list
+	 * of parts cannot hold the words of a phrase, and `Word.fromParts` cannot
+	 * build them back.
+	 */
+	get parts(): Array<WordPartObject>;
 	matches(sentence: Sentence): boolean;
+	/**
+	 * Matches a `Word` against a `Sentence` like `matches`, returning the
+	 * position of every match within the sentence's contents.
+	 */
+	find(sentence: Sentence): Array<WordMatch>;
+	/**
+	 * Matches the word against a text, returning a trace of every span,
+	 * candidate, and character that was read, along with the reason why each
+	 * candidate was abandoned.
+	 *
+	 * @param input - The text to match the word against.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const trace = new Word("**ass**").explain("Hello, class!");
+	 * // ⇒ { normalized: "hello, class!", pattern: "**ass**", matched: true, spans: [...] }
+	 * ```
+	 *
+	 * @remarks
+	 *
+	 * This method is intended for debugging patterns, as recording the trace
+	 * is slower than matching. Phrase patterns only trace their first word.
+	 */
+	explain(input: string): MatchTrace;
 	get length(): number;
 	toString(): string;
 }
+/**
+ * A compiled list of `Word`s that are matched together against a
+ * `Sentence`.
+ *
+ * The words are shared between the list and the tasks it spawns, so
+ * matching a batch in the background does not need to compile them again.
+ */
+export class WordList {
+	/**
+	 * @remarks
+	 *
+	 * If a pattern cannot be parsed, the thrown error has a `word` property
+	 * with the index of the offending pattern, besides `code` and `index`.
+	 */
+	constructor(words: Array<string>);
+	matches(sentence: Sentence): boolean;
+	/**
+	 * Matches the list against a batch of messages in the libuv thread pool,
+	 * resolving with one result per message, in the same order.
+	 *
+	 * @param messages - The messages to match against.
+	 * @param signal - An optional `AbortSignal` to cancel the task.
+	 *
+	 * @example
+	 *
+	 * ```ts
+	 * const list = new WordList(["pepe", "banana"]);
+	 * const results = await list.matchBatch(["Pepe ate a banana", "Hello world"]);
+	 * // ⇒ [{ matched: true, words: [0, 1], edits: [...] }, { matched: false, words: [], edits: [] }]
+	 * ```
+	 *
+	 * @remarks
+	 *
+	 * Aborting the signal rejects the promise with an `AbortError` once the
+	 * message being matched is done, and the rest of the messages are skipped.
+	 * A signal that is already aborted rejects without matching any message.
+	 */
+	matchBatch(messages: Array<string>, signal?: AbortSignal | undefined | null): Promise<Array<BatchMatchResult>>;
+	/**
+	 * Matches the list against a batch of messages in parallel, using as
+	 * many threads as CPU cores, returning one result per message, in the same
+	 * order.
+	 *
+	 * @param messages - The messages to match against.
+	 *
+	 * @remarks
+	 *
+	 * This method blocks the calling thread until every message has been
+	 * matched, it is intended for large offline batches, such as scanning
+	 * archived messages. Use `matchBatch` to avoid blocking the event loop.
+	 */
+	matchParallel(messages: Array<string>): Array<BatchMatchResult>;
+	get length(): number;
+}
+/** The kind of a `WordPart`, see `WordPartObject`. */
+export enum WordPartKind {
+	/** A single character, such as `"a"`. */
+	Single = 'single',
+	/** A group of characters, such as `"[abc]"`. */
+	Group = 'group',
+	/** A single wildcard, `"*"`. */
+	SingleWildcard = 'singleWildcard',
+	/** An any wildcard, `"**"`. */
+	AnyWildcard = 'anyWildcard'
+}
+/**
+ * A part of a `Word` as a plain object, used to read the parts of a word and
+ * to build a word without writing its pattern.
+ *
+ * @example
+ *
+ * ```ts
+ * const word = Word.fromParts([
+ * 	{ kind: "single", char: "b" },
+ * 	{ kind: "group", chars: ["a", "4"] },
+ * 	{ kind: "single", char: "r" },
+ * ]);
+ * word.toString();
+ * // ⇒ "b[a4]r"
+ * ```
+ */
+export interface WordPartObject {
+	/** The kind of the part. */
+	kind: WordPartKind;
+	/** The character of a `single` part. */
+	char?: string;
+	/** The characters of a `group` part. */
+	chars?: Array<string>;
+}
@@ -1,7 +1,22 @@
 import mod from './index.cjs';
 
 export default mod;
+export const { AbandonReason } = mod;
 export const { Boundary } = mod;
+export const { ContextRule } = mod;
+export const { ConversationBuffer } = mod;
+export const { FingerprintIndex } = mod;
+export const { MatchKind } = mod;
+export const { ParseErrorCode } = mod;
+export const { Protection } = mod;
+export const { ProximityRule } = mod;
+export const { RestrictionLevel } = mod;
 export const { Sentence } = mod;
+export const { SentenceSnapshot } = mod;
+export const { StreamScanner } = mod;
+export const { TraceStepKind } = mod;
 export const { Word } = mod;
+export const { WordList } = mod;
 export const { WordMatch } = mod;
+export const { WordPartKind } = mod;
+export const { fingerprintSimilarity } = mod;
@@ -0,0 +1,177 @@
+use unicode_normalization::char::is_combining_mark;
+
+use crate::protected::{read_reference, Protection};
+
+/// The amount of times a character must be repeated in a row to be counted
+/// as a flood, such as `"!!!!"`.
+const FLOOD_LENGTH: u32 = 4;
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// The names that mention every member after an `@`.
+const MASS_MENTIONS: [&[char]; 2] = [&['e', 'v', 'e', 'r', 'y', 'o', 'n', 'e'], &['h', 'e', 'r', 'e']];
+
+/// Signals of the original text of a `Sentence`, used to detect spam, see
+/// `Sentence#metrics`.
+#[derive(Clone, Default)]
+#[napi(object)]
+pub struct SpamMetrics {
+	/// The amount of letters in the message.
+	pub letters: u32,
+	/// The amount of uppercase letters in the message.
+	pub uppercase: u32,
+	/// The ratio of uppercase letters over all the letters, from `0` to `1`.
+	pub caps_ratio: f64,
+	/// The longest amount of times a character was repeated in a row, not
+	/// counting whitespace.
+	pub longest_repeat: u32,
+	/// The amount of times a character was repeated at least four times in a
+	/// row, such as `"!!!!"` or `"aaaaaa"`.
+	pub floods: u32,
+	/// The amount of combining marks in the message, which are stacked to
+	/// write zalgo text.
+	pub combining_marks: u32,
+	/// The ratio of combining marks over the rest of the visible characters,
+	/// which is greater than `1` when every character has several marks. This
+	/// is the zalgo score of the message.
+	pub zalgo_density: f64,
+	/// The largest amount of combining marks stacked on a single character.
+	pub longest_mark_stack: u32,
+	/// The amount of emoji in the message, including custom emoji, where
+	/// sequences such as flags or families are counted once.
+	pub emoji: u32,
+	/// Whether or not the message only has emoji and whitespace.
+	pub emoji_only: bool,
+	/// The amount of user mentions, such as `<@123>`.
+	pub mentions: u32,
+	/// The amount of role mentions, such as `<@&123>`.
+	pub role_mentions: u32,
+	/// The amount of `@everyone` and `@here` mentions.
+	pub mass_mentions: u32,
+}
+
+/// Computes the `SpamMetrics` of a message in a single pass over its
+/// characters.
+pub(crate) fn spam_metrics(original: &[char]) -> SpamMetrics {
+	let mut metrics = SpamMetrics::default();
+	// The amount of characters that are neither whitespace nor combining marks:
+	let mut visible = 0u32;
+	// Whether or not a character other than emoji and whitespace was read:
+	let mut other = false;
+	// The previous character, and the amount of times it was repeated:
+	let mut previous: Option<(char, u32)> = None;
+	// Whether or not the last regional indicator read starts a flag, as they form
+	// flags in pairs:
+	let mut flag_started = false;
+	// The amount of combining marks stacked on the last character:
+	let mut mark_stack = 0u32;
+	// The index of the character after the last reference that was read:
+	let mut skip_until = 0usize;
+
+	for (index, &c) in original.iter().enumerate() {
+		if index < skip_until {
+			continue;
+		}
+
+		// Mentions and custom emoji are read as a whole:
+		if c == '<' {
+			if let Some((end, kind)) = read_reference(original, index) {
+				match kind {
+					Protection::Mention => metrics.mentions += 1,
+					Protection::Role => metrics.role_mentions += 1,
+					Protection::Emoji => metrics.emoji += 1,
+					_ => {}
+				}
+
+				if kind != Protection::Emoji {
+					other = true;
+				}
+
+				skip_until = end;
+				previous = None;
+				continue;
+			}
+		}
+
+		if c == '@' && MASS_MENTIONS.iter().any(|name| original[index + 1..].starts_with(name)) {
+			metrics.mass_mentions += 1;
+		}
+
+		if c.is_whitespace() {
+			previous = None;
+			mark_stack = 0;
+			continue;
+		}
+
+		if c.is_alphabetic() {
+			metrics.letters += 1;
+			if c.is_uppercase() {
+				metrics.uppercase += 1;
+			}
+		}
+
+		let repeat = match previous {
+			Some((previous, repeat)) if previous == c => repeat + 1,
+			_ => 1,
+		};
+		metrics.longest_repeat = metrics.longest_repeat.max(repeat);
+		if repeat == FLOOD_LENGTH {
+			metrics.floods += 1;
+		}
+		previous = Some((c, repeat));
+
+		if is_emoji_component(c) {
+			continue;
+		}
+
+		if is_combining_mark(c) {
+			metrics.combining_marks += 1;
+			mark_stack += 1;
+			metrics.longest_mark_stack = metrics.longest_mark_stack.max(mark_stack);
+			continue;
+		}
+
+		mark_stack = 0;
+		visible += 1;
+		if is_regional_indicator(c) {
+			// Only count the first indicator of every pair:
+			if !flag_started {
+				metrics.emoji += 1;
+			}
+			flag_started = !flag_started;
+		} else if is_emoji(c) {
+			// Emoji joined to the previous one form a single emoji, such as families:
+			if index == 0 || original[index - 1] != ZERO_WIDTH_JOINER {
+				metrics.emoji += 1;
+			}
+		} else {
+			other = true;
+		}
+	}
+
+	if metrics.letters > 0 {
+		metrics.caps_ratio = f64::from(metrics.uppercase) / f64::from(metrics.letters);
+	}
+	if metrics.combining_marks > 0 {
+		metrics.zalgo_density = f64::from(metrics.combining_marks) / f64::from(visible.max(1));
+	}
+
+	metrics.emoji_only = metrics.emoji > 0 && !other;
+	metrics
+}
+
+/// Whether or not a character is an emoji, from the blocks of pictographs,
+/// symbols, and dingbats.
+fn is_emoji(c: char) -> bool {
+	matches!(c, '\u{1F300}'..='\u{1FAFF}' | '\u{2600}'..='\u{27BF}' | '\u{1F000}'..='\u{1F0FF}' | '\u{2B00}'..='\u{2BFF}')
+}
+
+fn is_regional_indicator(c: char) -> bool {
+	matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+/// Whether or not a character modifies an emoji rather than being one, such
+/// as skin tones, variation selectors, or joiners.
+fn is_emoji_component(c: char) -> bool {
+	matches!(c, '\u{1F3FB}'..='\u{1F3FF}' | '\u{FE00}'..='\u{FE0F}' | ZERO_WIDTH_JOINER | '\u{20E3}')
+}
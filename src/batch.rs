@@ -0,0 +1,115 @@
+use std::sync::{
+	atomic::{AtomicBool, Ordering},
+	Arc,
+};
+
+use napi::{Env, Error, Result, Status, Task};
+use rayon::prelude::*;
+
+use crate::{
+	sentence::{Sentence, SentenceEdit},
+	word::Word,
+	word_list::WordList,
+};
+
+// `Word` and `WordList` are shared between the threads that match a batch, a
+// `Sentence` is built and used within a single thread.
+const _: () = {
+	const fn assert_send_sync<T: Send + Sync>() {}
+	assert_send_sync::<Word>();
+	assert_send_sync::<WordList>();
+};
+
+/// The result of matching a `WordList` against a single message of a batch.
+#[derive(Clone)]
+#[napi(object)]
+pub struct BatchMatchResult {
+	/// Whether or not any of the words matched the message.
+	pub matched: bool,
+	/// The indexes of the words of the list that matched the message.
+	pub words: Vec<u32>,
+	/// The marked characters of the message as edits over the message, see
+	/// `Sentence#toEdits`.
+	pub edits: Vec<SentenceEdit>,
+}
+
+impl BatchMatchResult {
+	/// Builds a `Sentence` from `message` and matches `list` against it.
+	pub(crate) fn new(list: &WordList, message: &str) -> Self {
+		let mut sentence = Sentence::new(message.to_string());
+		let words = list.matches(&mut sentence);
+
+		BatchMatchResult { matched: !words.is_empty(), words, edits: sentence.edits("*") }
+	}
+}
+
+/// Matches `list` against every message in parallel, using the global Rayon
+/// thread pool, returning the results in the same order as `messages`.
+pub fn match_parallel<S: AsRef<str> + Sync>(list: &WordList, messages: &[S]) -> Vec<BatchMatchResult> {
+	messages.par_iter().map(|message| BatchMatchResult::new(list, message.as_ref())).collect()
+}
+
+/// A task that matches a `WordList` against a batch of messages outside of
+/// the main thread.
+pub struct MatchBatchTask {
+	pub(crate) list: WordList,
+	pub(crate) messages: Vec<String>,
+	/// Whether the signal passed to the task was aborted, which is checked
+	/// before matching every message.
+	pub(crate) aborted: Arc<AtomicBool>,
+}
+
+impl Task for MatchBatchTask {
+	type Output = Vec<BatchMatchResult>;
+	type JsValue = Vec<BatchMatchResult>;
+
+	fn compute(&mut self) -> Result<Self::Output> {
+		let mut results = Vec::with_capacity(self.messages.len());
+		for message in &self.messages {
+			if self.aborted.load(Ordering::Relaxed) {
+				return Err(Error::new(Status::Cancelled, "AbortError"));
+			}
+
+			results.push(BatchMatchResult::new(&self.list, message));
+		}
+
+		Ok(results)
+	}
+
+	fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+		Ok(output)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn match_parallel_returns_the_results_of_matches_in_order() {
+		let list = WordList::parse(&["pepe", "banana", "**ass**"]).unwrap();
+		let messages: Vec<String> = (0..500)
+			.map(|index| match index % 3 {
+				0 => format!("Pepe ate banana number {index}"),
+				1 => format!("Hello world {index}"),
+				_ => format!("Class {index} is bad ass"),
+			})
+			.collect();
+
+		let edits = |edits: &[SentenceEdit]| -> Vec<(u32, u32, String)> {
+			edits.iter().map(|edit| (edit.start, edit.end, edit.replacement.clone())).collect()
+		};
+
+		let results = match_parallel(&list, &messages);
+		assert_eq!(results.len(), messages.len());
+		assert_eq!(results.iter().filter(|result| result.matched).count(), 333);
+		for (result, message) in results.iter().zip(&messages) {
+			let mut sentence = Sentence::new(message.clone());
+			let words = list.matches(&mut sentence);
+
+			assert_eq!(result.matched, !words.is_empty());
+			assert_eq!(result.words, words);
+			assert_eq!(edits(&result.edits), edits(&sentence.edits("*")));
+		}
+	}
+}
@@ -2,3 +2,7 @@ pub const ASTERISK: char = '*';
 pub const ESCAPE: char = '\\';
 pub const GROUP_START: char = '[';
 pub const GROUP_END: char = ']';
+pub const FLAGS_START: &str = "(?";
+pub const FLAGS_END: char = ')';
+pub const FLAG_CASE_SENSITIVE: char = 'c';
+pub const FLAG_LITERAL: char = 'l';
@@ -0,0 +1,174 @@
+use napi::{Env, Error};
+
+use crate::{
+	sentence::{Sentence, SentenceSnapshot},
+	word::{Word, WordMatch},
+	word_list::parse_words,
+};
+
+/// Words looked for in the tokens around a match of a `ContextRule`.
+#[derive(Clone)]
+#[napi(object)]
+pub struct ContextCondition {
+	/// The patterns of the words, any of them fulfills the condition. The
+	/// words of a phrase must be found in consecutive tokens.
+	pub words: Vec<String>,
+	/// The amount of tokens next to the match that are searched, defaults to
+	/// `1`.
+	pub within: Option<u32>,
+}
+
+#[derive(Clone, Default)]
+#[napi(object)]
+pub struct ContextRuleOptions {
+	/// The words that must follow the match.
+	pub followed_by: Option<ContextCondition>,
+	/// The words that must precede the match.
+	pub preceded_by: Option<ContextCondition>,
+	/// The words that must not follow the match.
+	pub not_followed_by: Option<ContextCondition>,
+	/// The words that must not precede the match.
+	pub not_preceded_by: Option<ContextCondition>,
+}
+
+/// A parsed `ContextCondition`.
+#[derive(Clone)]
+struct Condition {
+	words: Vec<Word>,
+	within: usize,
+}
+
+impl Condition {
+	fn parse(env: &Env, condition: Option<ContextCondition>) -> Result<Option<Self>, Error> {
+		let Some(condition) = condition else {
+			return Ok(None);
+		};
+
+		Ok(Some(Condition {
+			words: parse_words(env, &condition.words)?,
+			within: condition.within.unwrap_or(1) as usize,
+		}))
+	}
+
+	/// Whether or not any of the words matches within `tokens`, where the
+	/// words of a phrase must match consecutive tokens. The marks of a match
+	/// are undone by restoring `unmarked`.
+	fn matches(&self, sentence: &mut Sentence, unmarked: &SentenceSnapshot, tokens: &[(usize, usize)]) -> bool {
+		self.words.iter().any(|word| {
+			let matched = !word.find_in_tokens(sentence, tokens).is_empty();
+			if matched {
+				sentence.apply_snapshot(unmarked);
+			}
+
+			matched
+		})
+	}
+}
+
+/// A `Word` that only matches depending on the words around it, such as
+/// `"kill"` followed by `"yourself"`, but not by `"the process"`.
+///
+/// The surrounding words are looked for in the tokens of the sentence, the
+/// runs of word characters next to the tokens of the match.
+///
+/// @example
+///
+/// ```ts
+/// const rule = new ContextRule(new Word("kill"), { followedBy: { words: ["yourself", "urself"], within: 2 } });
+/// rule.matches(new Sentence("go kill yourself"));
+/// // ⇒ true
+/// rule.matches(new Sentence("kill the process"));
+/// // ⇒ false
+/// ```
+#[napi]
+#[derive(Clone)]
+pub struct ContextRule {
+	word: Word,
+	followed_by: Option<Condition>,
+	preceded_by: Option<Condition>,
+	not_followed_by: Option<Condition>,
+	not_preceded_by: Option<Condition>,
+}
+
+#[napi]
+impl ContextRule {
+	/// @param word - The word to match.
+	/// @param options - The words that must or must not surround the matches.
+	///
+	/// @remarks
+	///
+	/// If a pattern cannot be parsed, the thrown error has a `word` property
+	/// with the index of the offending pattern within its condition, like the
+	/// ones thrown by `WordList`.
+	#[napi(constructor)]
+	pub fn new(env: Env, word: &Word, options: Option<ContextRuleOptions>) -> Result<Self, Error> {
+		let options = options.unwrap_or_default();
+		Ok(ContextRule {
+			word: word.clone(),
+			followed_by: Condition::parse(&env, options.followed_by)?,
+			preceded_by: Condition::parse(&env, options.preceded_by)?,
+			not_followed_by: Condition::parse(&env, options.not_followed_by)?,
+			not_preceded_by: Condition::parse(&env, options.not_preceded_by)?,
+		})
+	}
+
+	/// Matches the rule against a `Sentence`, returning the matches of the
+	/// word whose surrounding tokens fulfill the conditions of the rule. Only
+	/// those matches are marked.
+	pub fn find(&self, sentence: &mut Sentence) -> Vec<WordMatch> {
+		let snapshot = sentence.snapshot();
+		let found = self.word.find(sentence);
+		if found.is_empty() {
+			return found;
+		}
+
+		// The conditions are looked for without marks, so words already matched by
+		// other words still count:
+		sentence.reset_marks();
+		let unmarked = sentence.snapshot();
+
+		let tokens = sentence.tokens();
+		let mut accepted = Vec::new();
+		for found in found {
+			let first = tokens.partition_point(|(_, end)| *end <= found.start);
+			let last = tokens.partition_point(|(start, _)| *start < found.end);
+
+			let before = |within: usize| &tokens[first.saturating_sub(within)..first];
+			let after = |within: usize| &tokens[last..(last + within).min(tokens.len())];
+
+			if self.followed_by.as_ref().is_none_or(|c| c.matches(sentence, &unmarked, after(c.within)))
+				&& self.preceded_by.as_ref().is_none_or(|c| c.matches(sentence, &unmarked, before(c.within)))
+				&& !self.not_followed_by.as_ref().is_some_and(|c| c.matches(sentence, &unmarked, after(c.within)))
+				&& !self.not_preceded_by.as_ref().is_some_and(|c| c.matches(sentence, &unmarked, before(c.within)))
+			{
+				accepted.push(found);
+			}
+		}
+
+		// Undo the marks of the word, so only the accepted matches are marked:
+		sentence.apply_snapshot(&snapshot);
+
+		let mut matches = Vec::new();
+		for found in accepted {
+			sentence.mark_match(found, &mut matches);
+		}
+
+		if !matches.is_empty() {
+			sentence.record_pattern(&self.word);
+		}
+
+		matches
+	}
+
+	#[napi]
+	pub fn matches(&self, sentence: &mut Sentence) -> bool {
+		!self.find(sentence).is_empty()
+	}
+
+	/// Matches the rule against a `Sentence` like `matches`, returning the
+	/// position of every accepted match within the sentence's contents.
+	#[napi(js_name = "find")]
+	pub fn js_find(&self, sentence: &mut Sentence) -> Vec<WordMatch> {
+		self.find(sentence)
+	}
+}
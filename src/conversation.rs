@@ -0,0 +1,191 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{sentence::Sentence, word_list::WordList};
+
+/// The default amount of messages kept per conversation.
+const DEFAULT_SIZE: u32 = 5;
+
+/// The default amount of conversations kept at once.
+const DEFAULT_MAX_CONVERSATIONS: u32 = 1000;
+
+#[derive(Clone, Default)]
+#[napi(object)]
+pub struct ConversationBufferOptions {
+	/// The amount of most recent messages kept per conversation, defaults to
+	/// `5`.
+	pub size: Option<u32>,
+	/// The amount of conversations kept at once, defaults to `1000`. When a
+	/// message is pushed into a new conversation and the buffer is full, the
+	/// conversation that received a message the longest time ago is dropped.
+	pub max_conversations: Option<u32>,
+}
+
+/// The part of a `ConversationMatch` within one message.
+#[derive(Clone)]
+#[napi(object)]
+pub struct MessageRange {
+	/// The ID of the message.
+	pub id: String,
+	/// The UTF-16 offset of the first matched character within the message.
+	pub start: u32,
+	/// The UTF-16 offset after the last matched character within the message.
+	pub end: u32,
+}
+
+/// A match found by a `ConversationBuffer`, which may span several messages.
+#[derive(Clone)]
+#[napi(object)]
+pub struct ConversationMatch {
+	/// The index of the word of the list that matched.
+	pub word: u32,
+	/// The matched range of every message the match spans, from the oldest to
+	/// the newest message.
+	pub messages: Vec<MessageRange>,
+}
+
+/// A buffer of the most recent messages of every conversation (such as a
+/// channel or a user), matching a `WordList` across them to catch words
+/// split into several messages, such as `"b"`, `"a"`, `"r"`.
+///
+/// @example
+///
+/// ```ts
+/// const buffer = new ConversationBuffer(new WordList(["bar"]), { size: 3 });
+/// buffer.push("channel", "1", "b");
+/// buffer.push("channel", "2", "a");
+/// buffer.push("channel", "3", "r!");
+/// // ⇒ [{ word: 0, messages: [{ id: "1", start: 0, end: 1 }, { id: "2", start: 0, end: 1 }, { id: "3", start: 0, end: 1 }] }]
+/// ```
+#[napi]
+pub struct ConversationBuffer {
+	list: WordList,
+	size: usize,
+	max_conversations: usize,
+	/// The most recent messages of every conversation.
+	conversations: HashMap<String, Conversation>,
+	/// The amount of messages pushed so far, used to find the least recently
+	/// used conversation.
+	pushed: u64,
+}
+
+/// The messages kept for a conversation of a `ConversationBuffer`.
+#[derive(Default)]
+struct Conversation {
+	/// The ID and characters of the most recent messages.
+	messages: VecDeque<(String, Vec<char>)>,
+	/// The value of `ConversationBuffer::pushed` when the last message was
+	/// pushed.
+	last_push: u64,
+}
+
+#[napi]
+impl ConversationBuffer {
+	#[napi(constructor)]
+	pub fn new(list: &WordList, options: Option<ConversationBufferOptions>) -> Self {
+		let options = options.unwrap_or_default();
+		let size = options.size.unwrap_or(DEFAULT_SIZE).max(1) as usize;
+		let max_conversations = options.max_conversations.unwrap_or(DEFAULT_MAX_CONVERSATIONS).max(1) as usize;
+		ConversationBuffer { list: list.clone(), size, max_conversations, conversations: HashMap::new(), pushed: 0 }
+	}
+
+	/// Pushes a message into a conversation, dropping its oldest message if it
+	/// is full, and matches the word list against the messages it holds. If
+	/// the conversation is new and the buffer is full, the least recently used
+	/// conversation is dropped.
+	///
+	/// @param key - The key of the conversation, such as a channel or user ID.
+	/// @param id - The ID of the message.
+	/// @param message - The content of the message.
+	///
+	/// @returns The matches that end within the pushed message, as the others
+	/// were returned by previous calls.
+	#[napi]
+	pub fn push(&mut self, key: String, id: String, message: String) -> Vec<ConversationMatch> {
+		if !self.conversations.contains_key(&key) && self.conversations.len() >= self.max_conversations {
+			let oldest = self.conversations.iter().min_by_key(|(_, conversation)| conversation.last_push);
+			if let Some(oldest) = oldest.map(|(key, _)| key.clone()) {
+				self.conversations.remove(&oldest);
+			}
+		}
+
+		self.pushed += 1;
+		let conversation = self.conversations.entry(key).or_default();
+		conversation.last_push = self.pushed;
+
+		let messages = &mut conversation.messages;
+		if messages.len() == self.size {
+			messages.pop_front();
+		}
+
+		messages.push_back((id, message.chars().collect()));
+		find_matches(&self.list, messages)
+	}
+
+	/// Removes the messages of a conversation, or of all of them.
+	///
+	/// @param key - The key of the conversation, if omitted, all the
+	/// conversations are cleared.
+	#[napi]
+	pub fn clear(&mut self, key: Option<String>) {
+		match key {
+			Some(key) => {
+				self.conversations.remove(&key);
+			}
+			None => self.conversations.clear(),
+		}
+	}
+}
+
+/// Matches `list` against `messages` joined by line breaks, returning the
+/// matches that end within the last message.
+fn find_matches(list: &WordList, messages: &VecDeque<(String, Vec<char>)>) -> Vec<ConversationMatch> {
+	// The range of characters of every message within the combined text:
+	let mut bounds = Vec::with_capacity(messages.len());
+	let mut text = Vec::new();
+	for (_, message) in messages {
+		if !text.is_empty() {
+			text.push('\n');
+		}
+
+		bounds.push((text.len(), text.len() + message.len()));
+		text.extend(message);
+	}
+
+	let last_start = bounds.last().map_or(0, |(start, _)| *start);
+	let mut sentence = Sentence::new(text.iter().collect());
+
+	let mut matches = Vec::new();
+	for (index, word) in list.words.iter().enumerate() {
+		for found in word.find(&mut sentence) {
+			let start = sentence.origins[found.start].0;
+			let end = sentence.origins[found.end - 1].1;
+			if end <= last_start {
+				continue;
+			}
+
+			let ranges = messages
+				.iter()
+				.zip(&bounds)
+				.filter(|(_, (message_start, message_end))| start < *message_end && end > *message_start)
+				.map(|((id, message), (message_start, _))| {
+					let from = start.saturating_sub(*message_start);
+					let to = (end - message_start).min(message.len());
+					MessageRange {
+						id: id.clone(),
+						start: utf16_length(&message[..from]),
+						end: utf16_length(&message[..to]),
+					}
+				})
+				.collect();
+
+			matches.push((start, ConversationMatch { word: index as u32, messages: ranges }));
+		}
+	}
+
+	matches.sort_by_key(|(start, _)| *start);
+	matches.into_iter().map(|(_, found)| found).collect()
+}
+
+fn utf16_length(characters: &[char]) -> u32 {
+	characters.iter().map(|c| c.len_utf16() as u32).sum()
+}
@@ -0,0 +1,127 @@
+use std::fmt::{self, Display, Formatter};
+
+use napi::{Env, Error, JsObject, Result, Status};
+
+/// The code of a `ParseError`, exposed as the `code` property of the errors
+/// thrown when a pattern cannot be parsed.
+#[napi(string_enum)]
+#[derive(PartialEq)]
+pub enum ParseErrorCode {
+	/// A character group (`"[abc]"`) is never closed.
+	UnterminatedGroup,
+	/// The escape character is at the end of the pattern.
+	TrailingEscape,
+	/// The pattern has no parts.
+	EmptyPattern,
+	/// The pattern only has wildcards that unbind its boundaries.
+	WildcardOnly,
+	/// A part given to `Word.fromParts` is not valid.
+	InvalidPart,
+	/// A flag of the pattern (such as the `"c"` in `"(?c)"`) is not known.
+	UnknownFlag,
+	/// The flags of the pattern (`"(?c)"`) are never closed.
+	UnterminatedFlags,
+	/// The flags of the pattern (`"(?)"`) are empty.
+	EmptyFlags,
+	/// A whitespace character is escaped (`"a\ b"`) outside a group.
+	EscapedWhitespace,
+}
+
+/// An error found while parsing the pattern of a `Word`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError {
+	/// A character group that starts at `index` is never closed.
+	UnterminatedGroup { index: usize },
+	/// The escape character at `index` is at the end of the pattern.
+	TrailingEscape { index: usize },
+	/// The pattern has no parts.
+	EmptyPattern,
+	/// The pattern only has a wildcard, which starts at `index`.
+	WildcardOnly { index: usize },
+	/// The part at `index` of the list given to `Word.fromParts` is not valid.
+	InvalidPart { index: usize, reason: &'static str },
+	/// The flag at `index` of the pattern is not known.
+	UnknownFlag { index: usize },
+	/// The flags that start at `index` are never closed.
+	UnterminatedFlags { index: usize },
+	/// The flags that start at `index` are empty.
+	EmptyFlags { index: usize },
+	/// The escape character at `index` escapes a whitespace character.
+	EscapedWhitespace { index: usize },
+}
+
+impl ParseError {
+	/// The code of the error.
+	pub fn code(&self) -> ParseErrorCode {
+		match self {
+			ParseError::UnterminatedGroup { .. } => ParseErrorCode::UnterminatedGroup,
+			ParseError::TrailingEscape { .. } => ParseErrorCode::TrailingEscape,
+			ParseError::EmptyPattern => ParseErrorCode::EmptyPattern,
+			ParseError::WildcardOnly { .. } => ParseErrorCode::WildcardOnly,
+			ParseError::InvalidPart { .. } => ParseErrorCode::InvalidPart,
+			ParseError::UnknownFlag { .. } => ParseErrorCode::UnknownFlag,
+			ParseError::UnterminatedFlags { .. } => ParseErrorCode::UnterminatedFlags,
+			ParseError::EmptyFlags { .. } => ParseErrorCode::EmptyFlags,
+			ParseError::EscapedWhitespace { .. } => ParseErrorCode::EscapedWhitespace,
+		}
+	}
+
+	/// The position of the error. For patterns, this is the UTF-16 offset of
+	/// the offending character, as used by JavaScript strings, for parts, this
+	/// is the index of the offending part.
+	pub fn index(&self) -> usize {
+		match self {
+			ParseError::UnterminatedGroup { index }
+			| ParseError::TrailingEscape { index }
+			| ParseError::WildcardOnly { index }
+			| ParseError::InvalidPart { index, .. }
+			| ParseError::UnknownFlag { index }
+			| ParseError::UnterminatedFlags { index }
+			| ParseError::EmptyFlags { index }
+			| ParseError::EscapedWhitespace { index } => *index,
+			ParseError::EmptyPattern => 0,
+		}
+	}
+
+	/// Creates a JavaScript error with the message of the error, as well as
+	/// its `code` and `index` properties.
+	pub(crate) fn to_js_error(&self, env: &Env) -> Result<JsObject> {
+		let mut error = env.create_error(Error::new(Status::GenericFailure, self.to_string()))?;
+		error.set_named_property("code", self.code())?;
+		error.set_named_property("index", self.index() as u32)?;
+		Ok(error)
+	}
+
+	/// Converts the error into a `napi::Error` that throws the JavaScript
+	/// error created by `to_js_error`.
+	pub(crate) fn into_napi_error(self, env: &Env) -> Error {
+		match self.to_js_error(env) {
+			Ok(error) => Error::from(error.into_unknown()),
+			Err(error) => error,
+		}
+	}
+}
+
+impl Display for ParseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			ParseError::UnterminatedGroup { .. } => write!(f, "Unterminated character group"),
+			ParseError::TrailingEscape { .. } => write!(f, "Escape character cannot be at the end of the word"),
+			ParseError::EmptyPattern => write!(f, "The word cannot be empty"),
+			ParseError::WildcardOnly { .. } => write!(f, "Wildcards cannot be the only character in the word"),
+			ParseError::InvalidPart { reason, .. } => write!(f, "{reason}"),
+			ParseError::UnknownFlag { .. } => write!(f, "Unknown pattern flag"),
+			ParseError::UnterminatedFlags { .. } => write!(f, "Unterminated pattern flags"),
+			ParseError::EmptyFlags { .. } => write!(f, "Pattern flags cannot be empty"),
+			ParseError::EscapedWhitespace { .. } => write!(f, "Whitespace cannot be escaped outside a group"),
+		}
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for Error {
+	fn from(error: ParseError) -> Self {
+		Error::new(Status::GenericFailure, error.to_string())
+	}
+}
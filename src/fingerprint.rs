@@ -0,0 +1,198 @@
+use std::collections::VecDeque;
+
+use napi::{Error, Result};
+
+/// The amount of characters of every feature hashed into a fingerprint.
+const SHINGLE_LENGTH: usize = 3;
+
+/// Hashes a feature with 64-bit FNV-1a, which is stable across platforms and
+/// versions, unlike the hashers of the standard library.
+fn hash(feature: &[char]) -> u64 {
+	let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+	for c in feature {
+		for byte in (*c as u32).to_le_bytes() {
+			hash ^= u64::from(byte);
+			hash = hash.wrapping_mul(0x0100_0000_01b3);
+		}
+	}
+
+	hash
+}
+
+/// Computes the 64-bit SimHash of the normalized contents of a sentence.
+///
+/// Only the word characters are read, and repeated characters are collapsed,
+/// so changes in spacing, punctuation, or flooding barely change the result.
+/// The features are the overlapping slices of three characters.
+pub(crate) fn simhash(contents: &[char]) -> u64 {
+	let mut characters: Vec<char> = Vec::with_capacity(contents.len());
+	for c in contents.iter().filter(|c| c.is_alphanumeric()) {
+		if characters.last() != Some(c) {
+			characters.push(*c);
+		}
+	}
+
+	if characters.is_empty() {
+		return 0;
+	}
+
+	let mut weights = [0i32; 64];
+	for feature in characters.windows(SHINGLE_LENGTH.min(characters.len())) {
+		let hash = hash(feature);
+		for (bit, weight) in weights.iter_mut().enumerate() {
+			*weight += if hash >> bit & 1 == 1 { 1 } else { -1 };
+		}
+	}
+
+	weights
+		.iter()
+		.enumerate()
+		.filter(|(_, weight)| **weight > 0)
+		.fold(0u64, |fingerprint, (bit, _)| fingerprint | 1 << bit)
+}
+
+/// Formats a fingerprint as the string exposed to JavaScript, as numbers
+/// cannot hold 64 bits.
+pub(crate) fn format_fingerprint(fingerprint: u64) -> String {
+	format!("{fingerprint:016x}")
+}
+
+fn parse_fingerprint(fingerprint: &str) -> Result<u64> {
+	if fingerprint.len() != 16 {
+		return Err(Error::from_reason("A fingerprint must have 16 hexadecimal digits"));
+	}
+
+	u64::from_str_radix(fingerprint, 16)
+		.map_err(|_| Error::from_reason("A fingerprint must have 16 hexadecimal digits"))
+}
+
+fn similarity(a: u64, b: u64) -> f64 {
+	1.0 - f64::from((a ^ b).count_ones()) / 64.0
+}
+
+/// Computes how similar two fingerprints created by `Sentence#fingerprint`
+/// are, from `0` to `1`, where `1` means that they are equal.
+///
+/// @param a - The first fingerprint.
+/// @param b - The second fingerprint.
+///
+/// @example
+///
+/// ```ts
+/// const a = new Sentence("join my server at example").fingerprint();
+/// const b = new Sentence("j0in my s e r v e r at example!!").fingerprint();
+/// fingerprintSimilarity(a, b);
+/// // ⇒ 0.859375
+/// ```
+#[napi]
+pub fn fingerprint_similarity(a: String, b: String) -> Result<f64> {
+	Ok(similarity(parse_fingerprint(&a)?, parse_fingerprint(&b)?))
+}
+
+#[derive(Clone, Default)]
+#[napi(object)]
+pub struct FingerprintIndexOptions {
+	/// The amount of fingerprints kept, the oldest ones are removed once it is
+	/// reached, defaults to `1000`.
+	pub capacity: Option<u32>,
+	/// The minimum similarity of the fingerprints returned by `find`, from `0`
+	/// to `1`, defaults to `0.85`.
+	pub threshold: Option<f64>,
+}
+
+/// A fingerprint of a `FingerprintIndex` that is similar to the one looked
+/// up.
+#[derive(Clone)]
+#[napi(object)]
+pub struct FingerprintMatch {
+	/// The ID the fingerprint was inserted with.
+	pub id: String,
+	/// The fingerprint.
+	pub fingerprint: String,
+	/// The similarity between the fingerprint and the one looked up, from `0`
+	/// to `1`.
+	pub similarity: f64,
+}
+
+/// A bounded in-memory index of the most recent fingerprints, used to find
+/// whether a similar message was seen recently, such as during raids.
+///
+/// @example
+///
+/// ```ts
+/// const index = new FingerprintIndex({ capacity: 500 });
+/// index.insert("message-1", new Sentence("join my server at example").fingerprint());
+/// index.find(new Sentence("j0in my s e r v e r at example!!").fingerprint());
+/// // ⇒ [{ id: "message-1", fingerprint: "...", similarity: 0.859375 }]
+/// ```
+#[napi]
+pub struct FingerprintIndex {
+	entries: VecDeque<(String, u64)>,
+	capacity: usize,
+	threshold: f64,
+}
+
+#[napi]
+impl FingerprintIndex {
+	#[napi(constructor)]
+	pub fn new(options: Option<FingerprintIndexOptions>) -> Self {
+		let options = options.unwrap_or_default();
+		let capacity = options.capacity.unwrap_or(1000).max(1) as usize;
+		let threshold = options.threshold.unwrap_or(0.85);
+
+		Self { entries: VecDeque::with_capacity(capacity), capacity, threshold }
+	}
+
+	/// Inserts a fingerprint, removing the oldest one if the index is full.
+	///
+	/// @param id - An ID to identify the fingerprint, such as a message ID.
+	/// @param fingerprint - A fingerprint created by `Sentence#fingerprint`.
+	#[napi]
+	pub fn insert(&mut self, id: String, fingerprint: String) -> Result<()> {
+		let fingerprint = parse_fingerprint(&fingerprint)?;
+		if self.entries.len() == self.capacity {
+			self.entries.pop_front();
+		}
+
+		self.entries.push_back((id, fingerprint));
+		Ok(())
+	}
+
+	/// Finds the fingerprints of the index that are similar to a fingerprint,
+	/// sorted from the most to the least similar.
+	///
+	/// @param fingerprint - A fingerprint created by `Sentence#fingerprint`.
+	/// @param threshold - The minimum similarity of the returned fingerprints,
+	/// defaults to the threshold of the index.
+	#[napi]
+	pub fn find(&self, fingerprint: String, threshold: Option<f64>) -> Result<Vec<FingerprintMatch>> {
+		let fingerprint = parse_fingerprint(&fingerprint)?;
+		let threshold = threshold.unwrap_or(self.threshold);
+
+		let mut found: Vec<FingerprintMatch> = self
+			.entries
+			.iter()
+			.map(|(id, other)| (id, other, similarity(fingerprint, *other)))
+			.filter(|(_, _, similarity)| *similarity >= threshold)
+			.map(|(id, other, similarity)| FingerprintMatch {
+				id: id.clone(),
+				fingerprint: format_fingerprint(*other),
+				similarity,
+			})
+			.collect();
+
+		found.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+		Ok(found)
+	}
+
+	/// Removes all the fingerprints of the index.
+	#[napi]
+	pub fn clear(&mut self) {
+		self.entries.clear();
+	}
+
+	#[napi(getter)]
+	pub fn length(&self) -> u32 {
+		self.entries.len() as u32
+	}
+}
@@ -0,0 +1,113 @@
+use crate::word_part::WordPart;
+
+/// A match of the parts of a `Word` within a token, allowing edits.
+pub(crate) struct FuzzyMatch {
+	/// The index of the first matched character within the token.
+	pub start: usize,
+	/// The index after the last matched character within the token.
+	pub end: usize,
+	/// The amount of edits needed to turn the matched characters into the word.
+	pub distance: usize,
+}
+
+/// A cell of the alignment table, holding the lowest distance found and the
+/// index of the token where the alignment started.
+#[derive(Clone, Copy)]
+struct Cell {
+	distance: usize,
+	start: usize,
+}
+
+const UNREACHABLE: Cell = Cell { distance: usize::MAX, start: 0 };
+
+impl Cell {
+	fn add(self, cost: usize) -> Cell {
+		Cell { distance: self.distance.saturating_add(cost), start: self.start }
+	}
+
+	fn min(self, other: Cell) -> Cell {
+		if other.distance < self.distance {
+			other
+		} else {
+			self
+		}
+	}
+}
+
+/// Finds the closest alignment of `parts` within `token`, counting
+/// substitutions, insertions, deletions, and swaps of adjacent characters as
+/// one edit each.
+///
+/// Like in exact matching, a character repeating the previous part (such as
+/// the second `"a"` in `"baar"` for `"bar"`) is free, and an any wildcard
+/// matches any amount of characters. If the word is not bound to the left or
+/// to the right, the alignment may start or end anywhere within the token.
+///
+/// Returns `None` if the closest alignment needs more than `max_distance`
+/// edits, or more than one edit for every three characters of the word, as
+/// short words would otherwise match almost any short token.
+pub(crate) fn find_fuzzy(
+	parts: &[WordPart],
+	token: &[char],
+	bound_left: bool,
+	bound_right: bool,
+	max_distance: usize,
+) -> Option<FuzzyMatch> {
+	let (m, n) = (parts.len(), token.len());
+	let mut table = vec![vec![UNREACHABLE; n + 1]; m + 1];
+
+	// Aligning no parts costs one insertion per character when bound to the left,
+	// otherwise the alignment can start at any character:
+	for (j, cell) in table[0].iter_mut().enumerate() {
+		*cell = if bound_left { Cell { distance: j, start: 0 } } else { Cell { distance: 0, start: j } };
+	}
+
+	for i in 1..=m {
+		let part = &parts[i - 1];
+		for j in 0..=n {
+			let mut cell = if *part == WordPart::AnyWildcard {
+				table[i - 1][j]
+			} else {
+				// Deletion of the part:
+				table[i - 1][j].add(1)
+			};
+
+			if j > 0 {
+				let character = token[j - 1];
+				if *part == WordPart::AnyWildcard {
+					cell = cell.min(table[i][j - 1]);
+				} else {
+					let matched = part.matches(character, None);
+
+					// Substitution, or match if the part accepts the character:
+					cell = cell.min(table[i - 1][j - 1].add(usize::from(!matched)));
+					// Insertion of the character, or repetition if the part accepts it:
+					cell = cell.min(table[i][j - 1].add(usize::from(!matched)));
+					// Swap with the previous character:
+					if i > 1
+						&& j > 1 && !matched
+						&& part.matches(token[j - 2], None)
+						&& parts[i - 2].matches(character, None)
+					{
+						cell = cell.min(table[i - 2][j - 2].add(1));
+					}
+				}
+			}
+
+			table[i][j] = cell;
+		}
+	}
+
+	// Aligning all the parts must end at the last character when bound to the
+	// right, otherwise the alignment can end at any character:
+	let ends = if bound_right { n..=n } else { 0..=n };
+	let (end, cell) =
+		ends.map(|j| (j, table[m][j])).filter(|(j, cell)| cell.start < *j).min_by_key(|(_, cell)| cell.distance)?;
+
+	let characters = parts.iter().filter(|part| **part != WordPart::AnyWildcard).count();
+	if cell.distance > max_distance.min(characters / 3) {
+		return None;
+	}
+
+	Some(FuzzyMatch { start: cell.start, end, distance: cell.distance })
+}
@@ -3,8 +3,23 @@
 #[macro_use]
 extern crate napi_derive;
 
+pub mod analysis;
+pub mod batch;
 mod confusables;
 mod constants;
+pub mod context;
+pub mod conversation;
+pub mod error;
+pub mod fingerprint;
+mod fuzzy;
+mod markdown;
+mod phonetic;
+pub mod protected;
+pub mod proximity;
+pub mod scripts;
 pub mod sentence;
+pub mod stream;
+pub mod trace;
 pub mod word;
+pub mod word_list;
 mod word_part;
@@ -0,0 +1,74 @@
+/// The characters Discord uses for bold, italics, underline, strikethrough,
+/// and spoilers.
+const MARKERS: [char; 4] = ['*', '_', '~', '|'];
+
+/// Returns, for every character of `original`, whether or not it is a
+/// formatting marker within a word, such as the `"**"` in `"f**u**ck"`.
+///
+/// A run of markers is within a word if it is surrounded by word characters
+/// on both sides.
+pub(crate) fn transparent_markers(original: &[char]) -> Vec<bool> {
+	let mut transparent = vec![false; original.len()];
+
+	let mut i = 0usize;
+	while i < original.len() {
+		if !MARKERS.contains(&original[i]) {
+			i += 1;
+			continue;
+		}
+
+		let start = i;
+		while i < original.len() && MARKERS.contains(&original[i]) {
+			i += 1;
+		}
+
+		if start > 0 && original[start - 1].is_alphanumeric() && i < original.len() && original[i].is_alphanumeric() {
+			transparent[start..i].fill(true);
+		}
+	}
+
+	transparent
+}
+
+/// Reads an inline code span or a code block starting at the backticks at
+/// `start`, returning the index after its closing backticks.
+pub(crate) fn read_code(original: &[char], start: usize) -> Option<usize> {
+	let fence = original[start..].iter().take_while(|c| **c == '`').count();
+
+	let mut i = start + fence;
+	while i < original.len() {
+		if original[i] != '`' {
+			i += 1;
+			continue;
+		}
+
+		let run = original[i..].iter().take_while(|c| **c == '`').count();
+		if run == fence {
+			return Some(i + run);
+		}
+
+		i += run;
+	}
+
+	None
+}
+
+/// Reads a quote starting at the `>` at `start`, returning the index after
+/// its last character. A `"> "` quotes the rest of the line, a `">>> "`
+/// quotes the rest of the message.
+pub(crate) fn read_quote(original: &[char], start: usize) -> Option<usize> {
+	if start > 0 && original[start - 1] != '\n' {
+		return None;
+	}
+
+	if original[start..].starts_with(&['>', '>', '>', ' ']) {
+		return Some(original.len());
+	}
+
+	if !original[start..].starts_with(&['>', ' ']) {
+		return None;
+	}
+
+	let line = original[start..].iter().position(|c| *c == '\n').unwrap_or(original.len() - start);
+	Some(start + line)
+}
@@ -0,0 +1,122 @@
+use crate::word_part::WordPart;
+
+fn is_vowel(c: Option<char>) -> bool {
+	matches!(c, Some('a' | 'e' | 'i' | 'o' | 'u'))
+}
+
+/// The class a vowel is read as in a phonetic key, `'e'` and `'i'` share a
+/// class as they are often swapped (such as in `"sheit"`).
+fn vowel_class(c: char) -> char {
+	match c {
+		'a' => 'A',
+		'e' | 'i' => 'I',
+		'o' => 'O',
+		_ => 'U',
+	}
+}
+
+/// Computes the phonetic key of an English word, following the rules of
+/// Lawrence Philips' Metaphone, so words that sound alike (such as `"phuk"`
+/// and `"fuck"`, or `"kunt"` and `"cunt"`) share the same key.
+///
+/// Characters other than ASCII letters are ignored. Unlike Metaphone, vowels
+/// are kept as their class (see [`vowel_class`]), with consecutive vowels of
+/// the same class read once, so `"count"`, `"can't"`, and `"kind"` do not
+/// share the key of `"cunt"`.
+pub(crate) fn phonetic_key<I: IntoIterator<Item = char>>(word: I) -> String {
+	// Read the letters, collapsing repeated letters other than 'c':
+	let mut letters: Vec<char> = Vec::new();
+	for c in word.into_iter().filter(char::is_ascii_lowercase) {
+		if c == 'c' || letters.last() != Some(&c) {
+			letters.push(c);
+		}
+	}
+
+	let at = |index: usize| letters.get(index).copied();
+	let mut key = String::with_capacity(letters.len());
+	let mut i = 0usize;
+
+	// Skip or replace the letters that are read differently at the start:
+	match (at(0), at(1)) {
+		(Some('a'), Some('e')) | (Some('g' | 'k' | 'p'), Some('n')) | (Some('w'), Some('r')) => i = 1,
+		(Some('x'), _) => {
+			key.push('s');
+			i = 1;
+		}
+		(Some('w'), Some('h')) => {
+			key.push('w');
+			i = 2;
+		}
+		_ => {}
+	}
+
+	while i < letters.len() {
+		let c = letters[i];
+		let previous = if i == 0 { None } else { at(i - 1) };
+		let (next, after) = (at(i + 1), at(i + 2));
+
+		match c {
+			'a' | 'e' | 'i' | 'o' | 'u' => {
+				let class = vowel_class(c);
+				if !key.ends_with(class) {
+					key.push(class);
+				}
+			}
+			// Silent in a trailing "mb", such as "dumb":
+			'b' if previous == Some('m') && next.is_none() => {}
+			// "sch" is read as "sk", "ch" and "cia" as "x":
+			'c' if next == Some('h') && previous == Some('s') => key.push('k'),
+			'c' if next == Some('h') || (next == Some('i') && after == Some('a')) => key.push('x'),
+			// Silent in "sci", "sce", and "scy", otherwise "ci", "ce", and "cy" are read as "s":
+			'c' if matches!(next, Some('i' | 'e' | 'y')) => {
+				if previous != Some('s') {
+					key.push('s');
+				}
+			}
+			'c' => key.push('k'),
+			// "dge", "dgi", and "dgy" are read as "j", skipping the 'g':
+			'd' if next == Some('g') && matches!(after, Some('e' | 'i' | 'y')) => {
+				key.push('j');
+				i += 1;
+			}
+			'd' => key.push('t'),
+			// Silent in "gh" unless followed by a vowel, and in a trailing "gn":
+			'g' if next == Some('h') && !is_vowel(after) => {}
+			'g' if next == Some('n') && after.is_none() => {}
+			'g' if matches!(next, Some('i' | 'e' | 'y')) => key.push('j'),
+			'g' => key.push('k'),
+			// Only read before a vowel, and never after the letters it modifies:
+			'h' if is_vowel(next) && !matches!(previous, Some('c' | 's' | 'p' | 't' | 'g')) => key.push('h'),
+			'h' => {}
+			'k' if previous == Some('c') => {}
+			'p' if next == Some('h') => key.push('f'),
+			'q' => key.push('k'),
+			's' if next == Some('h') || (next == Some('i') && matches!(after, Some('o' | 'a'))) => key.push('x'),
+			't' if next == Some('i') && matches!(after, Some('o' | 'a')) => key.push('x'),
+			't' if next == Some('h') => key.push('0'),
+			// Silent in "tch":
+			't' if next == Some('c') && after == Some('h') => {}
+			'v' => key.push('f'),
+			// Only read before a vowel:
+			'w' | 'y' if is_vowel(next) => key.push(c),
+			'w' | 'y' => {}
+			'x' => key.push_str("ks"),
+			'z' => key.push('s'),
+			_ => key.push(c),
+		}
+
+		i += 1;
+	}
+
+	key
+}
+
+/// Computes the phonetic key of the parts of a `Word`, reading the first
+/// character of every group and ignoring the wildcards.
+pub(crate) fn phonetic_key_of_parts(parts: &[WordPart]) -> String {
+	phonetic_key(parts.iter().filter_map(|part| match part {
+		WordPart::Single(c) => Some(*c),
+		WordPart::Group(chars) => chars.first().copied(),
+		WordPart::SingleWildcard | WordPart::AnyWildcard => None,
+	}))
+}
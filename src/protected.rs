@@ -0,0 +1,139 @@
+use crate::markdown::{read_code, read_quote};
+
+/// The kind of a range of a `Sentence` that is never matched, see
+/// `SentenceOptions.protect`.
+#[napi]
+#[derive(Debug, PartialEq)]
+pub enum Protection {
+	/// A URL starting with `http://` or `https://`.
+	Url,
+	/// A user mention, such as `<@123>` or `<@!123>`.
+	Mention,
+	/// A channel reference, such as `<#123>`.
+	Channel,
+	/// A role mention, such as `<@&123>`.
+	Role,
+	/// A custom emoji, such as `<:kekw:123>` or `<a:kekw:123>`.
+	Emoji,
+	/// A range given in `SentenceOptions.ranges`.
+	Custom,
+	/// A code span or block, such as `` `code` `` or a fenced block.
+	Code,
+	/// A quote, either a line starting with `"> "`, or the rest of the message
+	/// after a line starting with `">>> "`.
+	Quote,
+}
+
+/// A range of characters of a string.
+#[derive(Clone)]
+#[napi(object)]
+pub struct TextRange {
+	/// The UTF-16 offset of the first character of the range, as used by
+	/// JavaScript strings.
+	pub start: u32,
+	/// The UTF-16 offset after the last character of the range, as used by
+	/// JavaScript strings.
+	pub end: u32,
+}
+
+/// A range of a `Sentence` that is never matched, see
+/// `Sentence#protectedRanges`.
+#[derive(Clone)]
+#[napi(object)]
+pub struct ProtectedRange {
+	/// The UTF-16 offset of the first character of the range in the original
+	/// sentence.
+	pub start: u32,
+	/// The UTF-16 offset after the last character of the range in the original
+	/// sentence.
+	pub end: u32,
+	/// The kind of the range.
+	pub kind: Protection,
+}
+
+/// Finds the ranges of `original` detected by the detectors of `kinds`,
+/// returning the range of characters and the kind of every one of them.
+pub(crate) fn detect(original: &[char], kinds: &[Protection]) -> Vec<(usize, usize, Protection)> {
+	let mut ranges = Vec::new();
+
+	let mut i = 0usize;
+	while i < original.len() {
+		let found = match original[i] {
+			'<' => read_reference(original, i),
+			'h' | 'H' => read_url(original, i).map(|end| (end, Protection::Url)),
+			'`' => read_code(original, i).map(|end| (end, Protection::Code)),
+			'>' => read_quote(original, i).map(|end| (end, Protection::Quote)),
+			_ => None,
+		};
+
+		match found {
+			Some((end, kind)) if kinds.contains(&kind) => {
+				ranges.push((i, end, kind));
+				i = end;
+			}
+			_ => i += 1,
+		}
+	}
+
+	ranges
+}
+
+/// Reads a URL starting at `start`, returning the index after its last
+/// character. Trailing punctuation is not considered part of the URL.
+fn read_url(original: &[char], start: usize) -> Option<usize> {
+	if start > 0 && original[start - 1].is_alphanumeric() {
+		return None;
+	}
+
+	let scheme = ["https://", "http://"].into_iter().find(|scheme| {
+		scheme.chars().enumerate().all(|(i, c)| original.get(start + i).is_some_and(|o| o.to_ascii_lowercase() == c))
+	})?;
+
+	let mut end = start + scheme.len();
+	while end < original.len() && !original[end].is_whitespace() && !matches!(original[end], '<' | '>' | '"') {
+		end += 1;
+	}
+
+	while end > start + scheme.len() && matches!(original[end - 1], '.' | ',' | ':' | ';' | '!' | '?' | '\'') {
+		end -= 1;
+	}
+
+	(end > start + scheme.len()).then_some(end)
+}
+
+/// Reads a Discord reference (a mention, channel, role, or custom emoji)
+/// starting at the `<` at `start`, returning the index after its closing `>`.
+pub(crate) fn read_reference(original: &[char], start: usize) -> Option<(usize, Protection)> {
+	let at = |index: usize| original.get(index).copied();
+
+	let (kind, mut i) = match (at(start + 1), at(start + 2)) {
+		(Some('@'), Some('!')) => (Protection::Mention, start + 3),
+		(Some('@'), Some('&')) => (Protection::Role, start + 3),
+		(Some('@'), _) => (Protection::Mention, start + 2),
+		(Some('#'), _) => (Protection::Channel, start + 2),
+		(Some(':'), _) => (Protection::Emoji, start + 2),
+		(Some('a'), Some(':')) => (Protection::Emoji, start + 3),
+		_ => return None,
+	};
+
+	// Custom emoji have a name before their ID, such as "kekw:":
+	if kind == Protection::Emoji {
+		let name_start = i;
+		while at(i).is_some_and(|c| c.is_ascii_alphanumeric() || c == '_') {
+			i += 1;
+		}
+
+		if i == name_start || at(i) != Some(':') {
+			return None;
+		}
+
+		i += 1;
+	}
+
+	let id_start = i;
+	while at(i).is_some_and(|c| c.is_ascii_digit()) {
+		i += 1;
+	}
+
+	(i > id_start && at(i) == Some('>')).then_some((i + 1, kind))
+}
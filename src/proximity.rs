@@ -0,0 +1,166 @@
+use napi::{Env, Error};
+
+use crate::{
+	sentence::Sentence,
+	word::{Word, WordMatch},
+	word_list::parse_words,
+};
+
+/// A match of a `ProximityRule`, spanning the matches of all its words.
+#[derive(Clone)]
+#[napi(object, object_from_js = false)]
+pub struct ProximityMatch {
+	/// The index of the first character of the span within the sentence's
+	/// contents.
+	pub start: u32,
+	/// The index after the last character of the span within the sentence's
+	/// contents.
+	pub end: u32,
+	/// The match of every word of the rule, in the same order as the
+	/// patterns.
+	pub matches: Vec<WordMatch>,
+}
+
+/// A match of one of the words of a `ProximityRule`, with the tokens it
+/// covers.
+struct Occurrence {
+	word: usize,
+	first_token: usize,
+	last_token: usize,
+	found: WordMatch,
+}
+
+/// A rule that matches when all its words are found close to each other, in
+/// any order, such as a target word within a few tokens of a threat word.
+///
+/// The distance between two matches is the difference between the indexes of
+/// their tokens, so adjacent words are at a distance of `1`.
+///
+/// @example
+///
+/// ```ts
+/// const rule = new ProximityRule(["kill", "**you**"], 3);
+/// rule.find(new Sentence("i will find you and kill you"));
+/// // ⇒ [{ start: 12, end: 24, matches: [{ start: 20, end: 24, ... }, { start: 12, end: 15, ... }] }]
+/// ```
+#[napi]
+#[derive(Clone)]
+pub struct ProximityRule {
+	words: Vec<Word>,
+	distance: usize,
+}
+
+#[napi]
+impl ProximityRule {
+	/// @param patterns - The patterns of the words, at least two.
+	/// @param distance - The maximum distance in tokens between the matches of
+	/// the words.
+	///
+	/// @remarks
+	///
+	/// If a pattern cannot be parsed, the thrown error has a `word` property
+	/// with the index of the offending pattern, like the ones thrown by
+	/// `WordList`.
+	#[napi(constructor)]
+	pub fn new(env: Env, patterns: Vec<String>, distance: u32) -> Result<Self, Error> {
+		if patterns.len() < 2 {
+			return Err(Error::from_reason("A proximity rule needs at least two patterns"));
+		}
+
+		Ok(ProximityRule { words: parse_words(&env, &patterns)?, distance: distance as usize })
+	}
+
+	/// Matches the rule against a `Sentence`, returning every span where all
+	/// the words were found within the maximum distance. Only the matches of
+	/// those spans are marked.
+	pub fn find(&self, sentence: &mut Sentence) -> Vec<ProximityMatch> {
+		let snapshot = sentence.snapshot();
+		let tokens = sentence.tokens();
+		let token_of = |index: usize| tokens.partition_point(|(_, end)| *end <= index);
+
+		// Find the matches of every word on the same marks, so they can overlap:
+		let mut occurrences = Vec::new();
+		for (word, pattern) in self.words.iter().enumerate() {
+			for found in pattern.find(sentence) {
+				let (first_token, last_token) = (token_of(found.start), token_of(found.end - 1));
+				occurrences.push(Occurrence { word, first_token, last_token, found });
+			}
+
+			sentence.apply_snapshot(&snapshot);
+		}
+
+		occurrences.sort_by_key(|occurrence| (occurrence.found.start, occurrence.word));
+
+		let mut matches = Vec::new();
+		let mut consumed = 0usize;
+		for (i, left) in occurrences.iter().enumerate() {
+			if left.found.start < consumed {
+				continue;
+			}
+
+			// Pick the closest match of every other word after the leftmost one, without
+			// overlapping the matches picked before it:
+			let mut picks: Vec<&Occurrence> = vec![left];
+			for word in (0..self.words.len()).filter(|word| *word != left.word) {
+				let pick = occurrences[i + 1..].iter().find(|occurrence| {
+					occurrence.word == word
+						&& occurrence.found.start >= left.found.end
+						&& picks.iter().all(|pick| {
+							occurrence.found.end <= pick.found.start || pick.found.end <= occurrence.found.start
+						})
+				});
+
+				match pick {
+					Some(pick) => picks.push(pick),
+					None => break,
+				}
+			}
+
+			if picks.len() < self.words.len() {
+				continue;
+			}
+
+			let farthest = picks.iter().map(|pick| pick.first_token).max().unwrap_or(0);
+			if farthest.saturating_sub(left.last_token) > self.distance {
+				continue;
+			}
+
+			// Marking a match expands it to the whole token, so a pick may not be marked
+			// after another pick within the same token, in which case the candidate is
+			// dropped:
+			let before = sentence.snapshot();
+			let mut marked = Vec::new();
+			if !picks.iter().all(|pick| sentence.mark_match(pick.found, &mut marked)) {
+				sentence.apply_snapshot(&before);
+				continue;
+			}
+
+			for pick in &picks {
+				sentence.record_pattern(&self.words[pick.word]);
+			}
+			picks.sort_by_key(|pick| pick.word);
+
+			let end = picks.iter().map(|pick| pick.found.end).max().unwrap_or(left.found.end);
+			matches.push(ProximityMatch {
+				start: left.found.start as u32,
+				end: end as u32,
+				matches: picks.iter().map(|pick| pick.found).collect(),
+			});
+			consumed = end;
+		}
+
+		matches
+	}
+
+	#[napi]
+	pub fn matches(&self, sentence: &mut Sentence) -> bool {
+		!self.find(sentence).is_empty()
+	}
+
+	/// Matches the rule against a `Sentence` like `matches`, returning the span
+	/// of every match within the sentence's contents.
+	#[napi(js_name = "find")]
+	pub fn js_find(&self, sentence: &mut Sentence) -> Vec<ProximityMatch> {
+		self.find(sentence)
+	}
+}
@@ -0,0 +1,110 @@
+use unicode_script::{Script, UnicodeScript};
+use unicode_security::{MixedScript, RestrictionLevel as SecurityRestrictionLevel, RestrictionLevelDetection};
+
+use crate::sentence::Sentence;
+
+/// The restriction levels defined by UTS #39, from the most to the least
+/// restrictive. A token conforms to a level if it conforms to any of the
+/// levels before it.
+#[napi]
+#[derive(PartialEq)]
+pub enum RestrictionLevel {
+	/// All the characters are ASCII.
+	AsciiOnly,
+	/// All the characters belong to a single script.
+	SingleScript,
+	/// The characters are Latin mixed with Han, Hiragana, and Katakana, with
+	/// Han and Bopomofo, or with Han and Hangul.
+	HighlyRestrictive,
+	/// The characters are Latin mixed with a single other recommended script,
+	/// other than Cyrillic or Greek.
+	ModeratelyRestrictive,
+	/// The characters mix scripts arbitrarily, such as Latin with Cyrillic.
+	MinimallyRestrictive,
+	/// Some of the characters are not allowed in identifiers, such as
+	/// mathematical alphanumeric symbols like `"𝕙"`.
+	Unrestricted,
+}
+
+impl From<SecurityRestrictionLevel> for RestrictionLevel {
+	fn from(level: SecurityRestrictionLevel) -> Self {
+		match level {
+			SecurityRestrictionLevel::ASCIIOnly => RestrictionLevel::AsciiOnly,
+			SecurityRestrictionLevel::SingleScript => RestrictionLevel::SingleScript,
+			SecurityRestrictionLevel::HighlyRestrictive => RestrictionLevel::HighlyRestrictive,
+			SecurityRestrictionLevel::ModeratelyRestrictive => RestrictionLevel::ModeratelyRestrictive,
+			SecurityRestrictionLevel::MinimallyRestrictive => RestrictionLevel::MinimallyRestrictive,
+			SecurityRestrictionLevel::Unrestricted => RestrictionLevel::Unrestricted,
+		}
+	}
+}
+
+/// The scripts of a token of a `Sentence`, see `Sentence#scripts`.
+#[derive(Clone)]
+#[napi(object)]
+pub struct TokenScripts {
+	/// The token, as written in the original sentence.
+	pub text: String,
+	/// The UTF-16 offset of the first character of the token in the original
+	/// sentence.
+	pub start: u32,
+	/// The UTF-16 offset after the last character of the token in the original
+	/// sentence.
+	pub end: u32,
+	/// The names of the scripts of the characters of the token, in order of
+	/// appearance, not including the characters shared by several scripts,
+	/// such as digits.
+	pub scripts: Vec<String>,
+	/// Whether or not the token mixes scripts, as defined by UTS #39: a token
+	/// mixing Han, Hiragana, and Katakana is still single-script.
+	pub mixed: bool,
+	/// The most restrictive level the token conforms to.
+	pub restriction_level: RestrictionLevel,
+}
+
+/// Reads the tokens of the original characters of a sentence, which are the
+/// runs of characters that produced word characters, along with their
+/// scripts.
+pub(crate) fn token_scripts(sentence: &Sentence) -> Vec<TokenScripts> {
+	let mut is_word = vec![false; sentence.original.len()];
+	for (c, (start, end)) in sentence.contents.iter().zip(&sentence.origins) {
+		if c.is_alphanumeric() {
+			is_word[*start..*end].fill(true);
+		}
+	}
+
+	let offsets = sentence.original_offsets();
+	let mut tokens = Vec::new();
+	let mut i = 0usize;
+	while i < is_word.len() {
+		if !is_word[i] {
+			i += 1;
+			continue;
+		}
+
+		let start = i;
+		while i < is_word.len() && is_word[i] {
+			i += 1;
+		}
+
+		let text: String = sentence.original[start..i].iter().collect();
+		let mut scripts: Vec<Script> = Vec::new();
+		for c in text.chars() {
+			let script = c.script();
+			if !matches!(script, Script::Common | Script::Inherited | Script::Unknown) && !scripts.contains(&script) {
+				scripts.push(script);
+			}
+		}
+
+		tokens.push(TokenScripts {
+			start: offsets[start].1 as u32,
+			end: offsets[i].1 as u32,
+			scripts: scripts.iter().map(|script| script.full_name().to_string()).collect(),
+			mixed: !text.as_str().is_single_script(),
+			restriction_level: text.as_str().detect_restriction_level().into(),
+			text,
+		});
+	}
+
+	tokens
+}
@@ -1,8 +1,21 @@
-use std::fmt;
+use std::{
+	cell::OnceCell,
+	fmt,
+	hash::{DefaultHasher, Hash, Hasher},
+};
 
 use napi::{Error, Result};
+use unicode_normalization::char::compose;
 
-use crate::confusables::Confusable;
+use crate::{
+	analysis::{spam_metrics, SpamMetrics},
+	confusables::Confusable,
+	fingerprint::{format_fingerprint, simhash},
+	markdown::transparent_markers,
+	protected::{detect, ProtectedRange, Protection, TextRange},
+	scripts::{token_scripts, TokenScripts},
+	word::{Word, WordMatch},
+};
 
 #[napi]
 #[derive(PartialEq)]
@@ -41,6 +54,167 @@ pub struct ToCensoredStringOptions {
 	pub original: String,
 }
 
+#[derive(Clone, Default)]
+#[napi(object)]
+pub struct SentenceOptions {
+	/// The kinds of ranges detected in the sentence that are never matched,
+	/// such as URLs or mentions, defaults to none.
+	pub protect: Option<Vec<Protection>>,
+	/// Custom ranges of the sentence that are never matched.
+	pub ranges: Option<Vec<TextRange>>,
+	/// Whether or not Discord formatting markers (`*`, `_`, `~`, and `|`)
+	/// within words are ignored, so `"f**u**ck"` reads as `"fuck"`, defaults
+	/// to `false`.
+	///
+	/// The ignored markers are censored along with the word they are in.
+	pub markdown: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[napi(object)]
+pub struct AcrosticOptions {
+	/// Whether or not the last alphanumeric character of every line is also
+	/// read, as a second word after the first characters, defaults to `false`.
+	pub last: Option<bool>,
+}
+
+#[derive(Clone)]
+#[napi(object)]
+pub struct ToEditsOptions {
+	/// The character to use to censor the marked characters, defaults to `'*'`.
+	pub character: Option<String>,
+}
+
+/// An edit to apply to the original sentence, replacing the characters
+/// between `start` and `end` with `replacement`.
+#[derive(Clone)]
+#[napi(object)]
+pub struct SentenceEdit {
+	/// The UTF-16 offset of the first replaced character in the original
+	/// sentence, as used by JavaScript strings.
+	pub start: u32,
+	/// The UTF-16 offset after the last replaced character in the original
+	/// sentence, as used by JavaScript strings.
+	pub end: u32,
+	/// The UTF-8 byte offset of the first replaced character in the original
+	/// sentence.
+	pub byte_start: u32,
+	/// The UTF-8 byte offset after the last replaced character in the original
+	/// sentence.
+	pub byte_end: u32,
+	/// The text that replaces the characters within the range.
+	pub replacement: String,
+}
+
+/// The normalization steps that changed a character of a `Sentence`.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub(crate) struct Normalization {
+	/// The original character is a confusable, and was replaced with its base
+	/// characters.
+	pub confusable: bool,
+	/// The character was lowercased.
+	pub case_folded: bool,
+}
+
+/// How much of a `Sentence` was marked, see `Sentence#statistics`.
+#[derive(Clone)]
+#[napi(object)]
+pub struct SentenceStatistics {
+	/// The amount of word characters of the sentence, not including the
+	/// protected ranges.
+	pub characters: u32,
+	/// The amount of word characters that were marked.
+	pub marked_characters: u32,
+	/// The ratio of word characters that were marked, from `0` to `1`.
+	pub marked_ratio: f64,
+	/// The amount of tokens of the sentence, the runs of word characters.
+	pub tokens: u32,
+	/// The amount of tokens with at least one marked character.
+	pub marked_tokens: u32,
+	/// The distinct words that marked characters of the sentence, in the
+	/// order they first matched.
+	pub patterns: Vec<MatchedPattern>,
+}
+
+/// A word that marked characters of a `Sentence`, see
+/// `SentenceStatistics.patterns`. Words with the same pattern but different
+/// options are reported separately.
+#[derive(Clone, PartialEq)]
+#[napi(object)]
+pub struct MatchedPattern {
+	/// The pattern of the word, including its flags.
+	pub pattern: String,
+	/// The maximum distance of the word, see `WordOptions.maxDistance`.
+	pub max_distance: u32,
+	/// Whether or not the word matches phonetically, see
+	/// `WordOptions.phonetic`.
+	pub phonetic: bool,
+	/// Whether or not the word matches backwards, see `WordOptions.reversed`.
+	pub reversed: bool,
+}
+
+/// Where a character of a `Sentence` comes from, and how it was normalized.
+#[derive(Clone)]
+#[napi(object)]
+pub struct CharacterProvenance {
+	/// The normalized character, as read by the matchers.
+	pub character: String,
+	/// The character of the original sentence that produced the normalized
+	/// character.
+	pub original: String,
+	/// The UTF-16 offset of the original character in the original sentence.
+	pub start: u32,
+	/// The UTF-16 offset after the original character in the original
+	/// sentence.
+	pub end: u32,
+	/// Whether or not the original character is a confusable (such as `"а"`,
+	/// the Cyrillic letter) that was replaced with its base characters.
+	pub confusable: bool,
+	/// Whether or not the character was lowercased.
+	pub case_folded: bool,
+}
+
+/// A copy of the marks of a `Sentence`, created with `Sentence#snapshot` and
+/// applied back with `Sentence#restore`.
+#[napi]
+#[derive(Clone)]
+pub struct SentenceSnapshot {
+	/// A hash of the contents and protected ranges of the sentence that
+	/// created the snapshot, see `Sentence::identity`.
+	sentence: u64,
+	spans: Vec<(usize, usize)>,
+	boundaries: Vec<Boundary>,
+	patterns: Vec<MatchedPattern>,
+}
+
+/// A copy of a `Sentence` whose characters were normalized with different
+/// steps, see `Sentence::view`.
+pub(crate) struct SentenceView {
+	pub(crate) sentence: Sentence,
+	/// The ranges of characters of the viewed sentence that share the same
+	/// origin, along with the range of characters they became in the view, as
+	/// `(start, end, view_start, view_end)`.
+	groups: Vec<(usize, usize, usize, usize)>,
+}
+
+impl SentenceView {
+	/// Converts a range of characters of the view into the range of characters
+	/// of the viewed sentence that produced them.
+	pub(crate) fn to_sentence_range(&self, start: usize, end: usize) -> (usize, usize) {
+		let first = self.groups.partition_point(|(_, _, _, view_end)| *view_end <= start);
+		let last = self.groups.partition_point(|(_, _, _, view_end)| *view_end < end);
+		(self.groups[first].0, self.groups[last].1)
+	}
+
+	/// Converts a range of characters of the viewed sentence into the range of
+	/// characters they became in the view.
+	pub(crate) fn to_view_range(&self, start: usize, end: usize) -> (usize, usize) {
+		let first = self.groups.partition_point(|(_, group_end, _, _)| *group_end <= start);
+		let last = self.groups.partition_point(|(_, group_end, _, _)| *group_end < end);
+		(self.groups[first].2, self.groups[last].3)
+	}
+}
+
 /// A struct that represents a sentence. The sentence is split into spans that
 /// are checked by the `Word` class. The spans are updated when a word is
 /// checked, and the indexes are updated to keep track of the words that have
@@ -56,21 +230,20 @@ pub struct Sentence {
 	/// a word is checked, the span is split into two spans, one that covers the
 	/// start of the sentence and the other that covers the end.
 	///
-	/// If only non-word characters are between two spans, they merge into a
-	/// single span. Spans are at least one character long. Surrounding non-word
-	/// characters are included in the span.
+	/// Spans are at least one character long. Surrounding non-word characters
+	/// are included in the span.
 	///
 	/// For example, if the sentence is "Pepe ate a banana", the `spans` vector
 	/// will be initialized with a single span (0, 17).
 	///
 	/// If the word "ate" is checked, the `spans` vector will be updated to the
 	/// spans:
-	/// - `(0, 3)` "Pepe"
-	/// - `(9, 17)` "a banana"
+	/// - `(0, 5)` "Pepe "
+	/// - `(8, 17)` " a banana"
 	///
 	/// If the word "Pepe" is checked, the `spans` vector will be updated to the
 	/// spans:
-	/// - `(9, 17)` "a banana"
+	/// - `(8, 17)` " a banana"
 	///
 	/// The `spans` vector is also always sorted by the start of the span.
 	pub(crate) spans: Vec<(usize, usize)>,
@@ -114,56 +287,209 @@ pub struct Sentence {
 	/// sentence. They are lowercased and have confusables replaced with their
 	/// base characters.
 	pub(crate) contents: Vec<char>,
+	/// The characters of the sentence as they were given to the constructor,
+	/// before any sanitization took place.
+	pub(crate) original: Vec<char>,
+	/// A vector with the same length as `contents`, where each entry is the
+	/// range of characters in `original` that produced the character at the
+	/// same position in `contents`.
+	///
+	/// A single original character may produce several characters (for
+	/// example, `"⑩"` becomes `"10"`), in which case all of them share the same
+	/// range. Likewise, the combining marks folded into a character (such as
+	/// zalgo stacks) extend its range.
+	pub(crate) origins: Vec<(usize, usize)>,
+	/// A vector with the same length as `contents`, where each entry holds the
+	/// normalization steps that changed the character at the same position.
+	pub(crate) normalizations: Vec<Normalization>,
+	/// The ranges of `contents` that are never matched, sorted and without
+	/// overlaps. Their characters are excluded from `spans`, and their
+	/// boundaries are always `NoContent`.
+	pub(crate) protected: Vec<(usize, usize, Protection)>,
+	/// Whether or not the characters of `contents` come from scattered
+	/// characters of `original`, as in acrostics, in which case the marked
+	/// characters are never censored along with the characters between them.
+	pub(crate) sparse: bool,
+	/// The distinct words that marked characters of the sentence, in the
+	/// order they first matched.
+	pub(crate) patterns: Vec<MatchedPattern>,
+	/// The spam signals of the original sentence, computed the first time
+	/// they are read, see `Sentence#metrics`.
+	pub(crate) metrics: OnceCell<SpamMetrics>,
+	/// The hash that tells apart the snapshots of other sentences, computed
+	/// the first time a snapshot is created, see `Sentence::identity`.
+	identity: OnceCell<u64>,
 }
 
 #[napi]
 impl Sentence {
+	/// @param sentence - The sentence to match words against.
+	/// @param options - The options of the sentence.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const sentence = new Sentence("<@123> check https://example.com/ass", {
+	/// 	protect: [Protection.Mention, Protection.Url],
+	/// });
+	/// new Word("**ass**").matches(sentence);
+	/// // ⇒ false
+	/// ```
 	#[napi(constructor)]
+	pub fn with_options(sentence: String, options: Option<SentenceOptions>) -> Self {
+		let options = options.unwrap_or_default();
+		let mut sentence = Self::read(sentence, options.markdown.unwrap_or(false));
+
+		let mut ranges = detect(&sentence.original, &options.protect.unwrap_or_default());
+		if let Some(custom) = options.ranges {
+			let offsets = sentence.original_offsets();
+			let index_of = |offset: u32| offsets.partition_point(|(_, utf16)| *utf16 < offset as usize);
+
+			for range in custom {
+				let (start, end) = (index_of(range.start), index_of(range.end).min(sentence.original.len()));
+				if start < end {
+					ranges.push((start, end, Protection::Custom));
+				}
+			}
+		}
+
+		sentence.protect(ranges);
+		sentence
+	}
+
+	/// Creates a sentence without protected ranges.
 	pub fn new(sentence: String) -> Self {
-		let sentence = sentence.replace_confusables().to_lowercase();
-		let mut boundaries: Vec<Boundary> = Vec::with_capacity(sentence.len());
-		let contents: Vec<char> = sentence.chars().collect();
+		Self::read(sentence, false)
+	}
 
-		let mut chars = sentence.chars().peekable();
-		while let Some(c) = chars.next() {
-			// If the character is a whitespace or control character, the boundary is no
-			// content.
-			if !c.is_alphanumeric() {
-				boundaries.push(Boundary::NoContent);
+	/// Reads the contents of a sentence, ignoring the formatting markers within
+	/// words if `markdown` is `true`.
+	fn read(sentence: String, markdown: bool) -> Self {
+		let original: Vec<char> = sentence.chars().collect();
+		let transparent = if markdown { transparent_markers(&original) } else { vec![false; original.len()] };
+		let mut contents: Vec<char> = Vec::with_capacity(original.len());
+		let mut origins: Vec<(usize, usize)> = Vec::with_capacity(original.len());
+		let mut normalizations: Vec<Normalization> = Vec::with_capacity(original.len());
+
+		for (index, c) in original.iter().enumerate() {
+			if transparent[index] {
 				continue;
-			};
+			}
 
-			// Read the following characters until the end of `sentence` or until a
-			// whitespace or control character is found.
-			//
-			// The first character is always the start of a word, so the boundary is set to
-			// start. Then, we scan the following characters until a whitespace or control
-			// character is found.
-			let mut boundary = Boundary::Start;
-			while let Some(c) = chars.peek() {
-				if !c.is_alphanumeric() {
-					break;
+			// Fold the combining marks that are not word characters into the character
+			// before them, so zalgo text does not split words apart, and the whole cluster
+			// is censored along with its character. A single accent was composed with its
+			// character instead, see `compose_single_mark`:
+			if is_foldable_mark(*c) {
+				if let Some(&(origin, _)) = origins.last() {
+					for (_, end) in origins.iter_mut().rev().take_while(|(start, _)| *start == origin) {
+						*end = index + 1;
+					}
+
+					continue;
 				}
+			}
 
-				// There is a word character, push the previous character's boundary, increase
-				// the word size, and set the boundary to `Word`.
-				boundaries.push(boundary);
-				boundary = Boundary::Word;
-				chars.next();
+			let c = compose_single_mark(*c, &original[index + 1..]).unwrap_or(*c);
+			let confusable = c.contains_confusables();
+			let replaced = c.replace_confusables();
+			let lowercased = replaced.to_lowercase();
+			let normalization = Normalization { confusable, case_folded: lowercased != replaced };
+
+			for c in lowercased.chars() {
+				contents.push(c);
+				origins.push((index, index + 1));
+				normalizations.push(normalization);
 			}
+		}
 
-			// Process the last character from the loop.
-			boundaries.push(if boundary == Boundary::Start {
-				// If the boundary is the start, the size is 1, and the character is a word,
-				// therefore the boundary is mixed.
-				Boundary::Mixed
-			} else {
-				// If the size is greater than 1, the boundary is a word.
-				Boundary::End
-			});
+		let boundaries = Self::read_boundaries(&contents);
+		Self {
+			spans: vec![(0usize, contents.len())],
+			boundaries,
+			contents,
+			original,
+			origins,
+			normalizations,
+			protected: Vec::new(),
+			sparse: false,
+			patterns: Vec::new(),
+			metrics: OnceCell::new(),
+			identity: OnceCell::new(),
 		}
+	}
+
+	/// Creates a virtual sentence from the first alphanumeric character of
+	/// every line of a text, so words written down the start of the lines are
+	/// matched. The marks are mapped back to the original text, so `toEdits`
+	/// and `toCensoredString` only censor the characters that were read.
+	///
+	/// @param sentence - The text to read the lines of.
+	/// @param options - The options of the acrostic.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const sentence = Sentence.acrostic("Big news\nAll of you\nRead this");
+	/// new Word("bar").matches(sentence);
+	/// // ⇒ true
+	/// sentence.toEdits();
+	/// // ⇒ [{ start: 0, end: 1, ... }, { start: 9, end: 10, ... }, { start: 20, end: 21, ... }]
+	/// ```
+	#[napi(factory)]
+	pub fn acrostic(sentence: String, options: Option<AcrosticOptions>) -> Self {
+		let last = options.unwrap_or_default().last.unwrap_or(false);
+		let full = Self::read(sentence, false);
+
+		// The line of every original character:
+		let mut line = 0usize;
+		let lines: Vec<usize> = full
+			.original
+			.iter()
+			.map(|c| {
+				let current = line;
+				if *c == '\n' {
+					line += 1;
+				}
 
-		Self { spans: vec![(0usize, contents.len())], boundaries, contents }
+				current
+			})
+			.collect();
+
+		// The indexes of the first and last alphanumeric characters of every line:
+		let mut columns: Vec<(usize, usize, usize)> = Vec::new();
+		for index in (0..full.contents.len()).filter(|index| full.contents[*index].is_alphanumeric()) {
+			let line = lines[full.origins[index].0];
+			match columns.last_mut() {
+				Some((previous, _, end)) if *previous == line => *end = index,
+				_ => columns.push((line, index, index)),
+			}
+		}
+
+		let mut indexes: Vec<Option<usize>> = columns.iter().map(|(_, first, _)| Some(*first)).collect();
+		if last {
+			// The columns are split by a separator that has no original characters:
+			indexes.push(None);
+			indexes.extend(columns.iter().map(|(_, _, end)| Some(*end)));
+		}
+
+		let end = full.original.len();
+		let contents: Vec<char> = indexes.iter().map(|index| index.map_or(' ', |index| full.contents[index])).collect();
+		let origins = indexes.iter().map(|index| index.map_or((end, end), |index| full.origins[index])).collect();
+		let normalizations = indexes
+			.iter()
+			.map(|index| index.map_or_else(Normalization::default, |index| full.normalizations[index]))
+			.collect();
+
+		Self {
+			spans: vec![(0usize, contents.len())],
+			boundaries: Self::read_boundaries(&contents),
+			contents,
+			origins,
+			normalizations,
+			sparse: true,
+			..full
+		}
 	}
 
 	/// Gets the length of the array. This is a number one higher than the
@@ -207,20 +533,27 @@ impl Sentence {
 	///
 	/// @remarks
 	///
-	/// The original sentence must have the same length as the sentence, or an
-	/// error will be thrown.
+	/// The original sentence must have as many characters as the text the
+	/// sentence was read from, or an error will be thrown. Like `toEdits`, the
+	/// marks are mapped back to the characters they were read from, so
+	/// formatting markers within a censored word are censored as well.
 	#[napi(js_name = "toCensoredString")]
 	pub fn js_to_censored_string(&self, options: ToCensoredStringOptions) -> Result<String> {
-		let mut out = String::with_capacity(self.contents.len());
 		let character = options.character.unwrap_or('*'.to_string());
 		let original = options.original;
 
-		if original.len() != self.contents.len() {
+		if original.chars().count() != self.original.len() {
 			return Err(Error::from_reason("The original sentence must have the same length as the sentence"));
 		}
 
-		for (index, c) in original.chars().enumerate() {
-			if self.boundaries[index] == Boundary::Marked {
+		let mut marked = vec![false; self.original.len()];
+		for (start, end) in self.marked_ranges() {
+			marked[start..end].fill(true);
+		}
+
+		let mut out = String::with_capacity(original.len());
+		for (c, marked) in original.chars().zip(marked) {
+			if marked {
 				out.push_str(&character);
 			} else {
 				out.push(c);
@@ -230,82 +563,582 @@ impl Sentence {
 		Ok(out)
 	}
 
+	/// Returns the marked characters of the sentence as a list of edits over
+	/// the original sentence, so they can be applied by the caller.
+	///
+	/// @param options - The options to use when censoring the marked
+	/// characters.
+	///
+	/// @returns The edits, ordered by their position and without overlaps.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const sentence = new Sentence("Pepe ate a banana");
+	/// const word = new Word("Pepe");
+	///
+	/// word.matches(sentence);
+	/// sentence.toEdits({ character: "X" });
+	/// // ⇒ [{ start: 0, end: 4, byteStart: 0, byteEnd: 4, replacement: "XXXX" }]
+	/// ```
+	#[napi(js_name = "toEdits")]
+	pub fn js_to_edits(&self, options: Option<ToEditsOptions>) -> Vec<SentenceEdit> {
+		let character = options.and_then(|options| options.character).unwrap_or('*'.to_string());
+		self.edits(&character)
+	}
+
+	/// Returns the marked characters of the sentence as a list of edits over
+	/// the original sentence, replacing every character with `character`.
+	pub(crate) fn edits(&self, character: &str) -> Vec<SentenceEdit> {
+		let offsets = self.original_offsets();
+
+		self.marked_ranges()
+			.into_iter()
+			.map(|(start, end)| SentenceEdit {
+				start: offsets[start].1 as u32,
+				end: offsets[end].1 as u32,
+				byte_start: offsets[start].0 as u32,
+				byte_end: offsets[end].0 as u32,
+				replacement: character.repeat(end - start),
+			})
+			.collect()
+	}
+
+	/// Returns where every character of the sentence comes from, and how it
+	/// was normalized, in the same order as the characters of `toString`.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const sentence = new Sentence("Pаy");
+	/// sentence.provenance();
+	/// // ⇒ [
+	/// // 	{ character: "p", original: "P", start: 0, end: 1, confusable: false, caseFolded: true },
+	/// // 	{ character: "a", original: "а", start: 1, end: 2, confusable: true, caseFolded: false },
+	/// // 	{ character: "y", original: "y", start: 2, end: 3, confusable: false, caseFolded: false },
+	/// // ]
+	/// ```
+	#[napi]
+	pub fn provenance(&self) -> Vec<CharacterProvenance> {
+		let offsets = self.original_offsets();
+
+		self.contents
+			.iter()
+			.zip(&self.origins)
+			.zip(&self.normalizations)
+			.map(|((character, (start, end)), normalization)| CharacterProvenance {
+				character: character.to_string(),
+				original: self.original[*start..*end].iter().collect(),
+				start: offsets[*start].1 as u32,
+				end: offsets[*end].1 as u32,
+				confusable: normalization.confusable,
+				case_folded: normalization.case_folded,
+			})
+			.collect()
+	}
+
+	/// Returns the scripts of every token of the original sentence, flagging
+	/// the tokens that mix scripts, such as a Cyrillic `"р"` within `"paypal"`.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const sentence = new Sentence("Pay with рaypal");
+	/// sentence.scripts().filter((token) => token.mixed);
+	/// // ⇒ [{ text: "рaypal", start: 9, end: 15, scripts: ["Cyrillic", "Latin"], mixed: true, restrictionLevel: RestrictionLevel.MinimallyRestrictive }]
+	/// ```
+	#[napi]
+	pub fn scripts(&self) -> Vec<TokenScripts> {
+		token_scripts(self)
+	}
+
+	/// The spam signals of the original sentence, such as its ratio of
+	/// uppercase letters, or its amount of mentions.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const sentence = new Sentence("HELLO <@123> <@456> 🎉🎉🎉🎉");
+	/// sentence.metrics;
+	/// // ⇒ { letters: 5, uppercase: 5, capsRatio: 1, longestRepeat: 4, floods: 1, mentions: 2, ... }
+	/// ```
+	///
+	/// @remarks
+	///
+	/// The metrics are computed from the original sentence the first time they
+	/// are read, so matching words does not pay for them.
+	#[napi(getter)]
+	pub fn metrics(&self) -> SpamMetrics {
+		self.metrics.get_or_init(|| spam_metrics(&self.original)).clone()
+	}
+
+	/// Computes the fingerprint of the sentence, a 64-bit SimHash of its
+	/// normalized contents as 16 hexadecimal digits. Sentences that differ in
+	/// confusables, spacing, or punctuation have similar fingerprints, see
+	/// `fingerprintSimilarity`.
+	///
+	/// @remarks
+	///
+	/// The fingerprint does not depend on the marks of the sentence.
+	#[napi]
+	pub fn fingerprint(&self) -> String {
+		format_fingerprint(simhash(&self.contents))
+	}
+
+	/// Saves the marks of the sentence, so they can be restored later with
+	/// `restore`.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const sentence = new Sentence("Pepe ate a banana");
+	/// const snapshot = sentence.snapshot();
+	///
+	/// new Word("Pepe").matches(sentence);
+	/// sentence.restore(snapshot);
+	/// // "Pepe" is no longer marked
+	/// ```
+	#[napi]
+	pub fn snapshot(&self) -> SentenceSnapshot {
+		SentenceSnapshot {
+			sentence: self.identity(),
+			spans: self.spans.clone(),
+			boundaries: self.boundaries.clone(),
+			patterns: self.patterns.clone(),
+		}
+	}
+
+	/// Restores the marks of the sentence to the state they had when
+	/// `snapshot` was called.
+	///
+	/// @param snapshot - A snapshot created by this sentence.
+	///
+	/// @remarks
+	///
+	/// The snapshot must have been created by the same sentence, or by one with
+	/// the same contents and protected ranges, or an error will be thrown.
+	#[napi]
+	pub fn restore(&mut self, snapshot: &SentenceSnapshot) -> Result<()> {
+		if snapshot.sentence != self.identity() {
+			return Err(Error::from_reason("The snapshot must have been created by the same sentence"));
+		}
+
+		self.apply_snapshot(snapshot);
+		Ok(())
+	}
+
+	/// Hashes the contents and the protected ranges of the sentence, which
+	/// never change after it is created, to tell apart the snapshots of other
+	/// sentences.
+	fn identity(&self) -> u64 {
+		*self.identity.get_or_init(|| {
+			let mut hasher = DefaultHasher::new();
+			self.contents.hash(&mut hasher);
+			for (start, end, _) in &self.protected {
+				(start, end).hash(&mut hasher);
+			}
+
+			hasher.finish()
+		})
+	}
+
+	/// Restores the marks of a snapshot created by this sentence.
+	pub(crate) fn apply_snapshot(&mut self, snapshot: &SentenceSnapshot) {
+		self.spans.clone_from(&snapshot.spans);
+		self.boundaries.clone_from(&snapshot.boundaries);
+		self.patterns.clone_from(&snapshot.patterns);
+	}
+
+	/// Removes all the marks of the sentence, restoring it to the state it had
+	/// when it was created.
+	#[napi]
+	pub fn reset_marks(&mut self) {
+		self.spans = vec![(0usize, self.contents.len())];
+		self.boundaries = Self::read_boundaries(&self.contents);
+		self.patterns.clear();
+		self.apply_protections();
+	}
+
+	/// Counts the marked characters and tokens of the sentence, as well as the
+	/// patterns that marked them, for thresholds such as censoring the whole
+	/// message when most of it was marked.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const sentence = new Sentence("Pepe ate a banana");
+	/// new WordList(["pepe", "banana"]).matches(sentence);
+	/// sentence.statistics();
+	/// // ⇒ { characters: 14, markedCharacters: 10, markedRatio: 0.7142857142857143, tokens: 4, markedTokens: 2, patterns: [{ pattern: "pepe", ... }, { pattern: "banana", ... }] }
+	/// ```
+	#[napi]
+	pub fn statistics(&self) -> SentenceStatistics {
+		let tokens = self.tokens();
+		let is_marked = |index: &usize| self.boundaries[*index] == Boundary::Marked;
+
+		let characters: usize = tokens.iter().map(|(start, end)| end - start).sum();
+		let marked_characters: usize = tokens.iter().map(|(start, end)| (*start..*end).filter(is_marked).count()).sum();
+		let marked_tokens = tokens.iter().filter(|(start, end)| (*start..*end).any(|index| is_marked(&index))).count();
+
+		SentenceStatistics {
+			characters: characters as u32,
+			marked_characters: marked_characters as u32,
+			marked_ratio: if characters == 0 { 0.0 } else { marked_characters as f64 / characters as f64 },
+			tokens: tokens.len() as u32,
+			marked_tokens: marked_tokens as u32,
+			patterns: self.patterns.clone(),
+		}
+	}
+
+	/// Records a word that marked characters of the sentence.
+	pub(crate) fn record_pattern(&mut self, word: &Word) {
+		let pattern = MatchedPattern {
+			pattern: word.pattern(),
+			max_distance: word.max_distance,
+			phonetic: word.phonetic,
+			reversed: word.reversed,
+		};
+
+		if !self.patterns.contains(&pattern) {
+			self.patterns.push(pattern);
+		}
+	}
+
+	/// Returns the ranges of the original sentence that are never matched,
+	/// sorted by their position.
+	#[napi]
+	pub fn protected_ranges(&self) -> Vec<ProtectedRange> {
+		let offsets = self.original_offsets();
+
+		self.protected
+			.iter()
+			.map(|(start, end, kind)| ProtectedRange {
+				start: offsets[self.origins[*start].0].1 as u32,
+				end: offsets[self.origins[*end - 1].1].1 as u32,
+				kind: *kind,
+			})
+			.collect()
+	}
+
+	/// Protects the given ranges of `original`, merging the overlapping ones,
+	/// which keep the kind of the first one.
+	fn protect(&mut self, mut ranges: Vec<(usize, usize, Protection)>) {
+		ranges.sort_by_key(|(start, end, _)| (*start, *end));
+
+		for (start, end, kind) in ranges {
+			// Convert the range of original characters into a range of contents:
+			let start = self.origins.partition_point(|(origin, _)| *origin < start);
+			let end = self.origins.partition_point(|(origin, _)| *origin < end);
+			if start == end {
+				continue;
+			}
+
+			match self.protected.last_mut() {
+				Some((_, last_end, _)) if start <= *last_end => *last_end = end.max(*last_end),
+				_ => self.protected.push((start, end, kind)),
+			}
+		}
+
+		self.apply_protections();
+	}
+
+	/// Removes the protected ranges from `spans`, and sets their boundaries to
+	/// `NoContent`.
+	fn apply_protections(&mut self) {
+		for (start, end, _) in self.protected.clone() {
+			self.spans = self
+				.spans
+				.iter()
+				.flat_map(|(span_start, span_end)| {
+					[(*span_start, start.min(*span_end)), (end.max(*span_start), *span_end)]
+				})
+				.filter(|(span_start, span_end)| span_start < span_end)
+				.collect();
+
+			for boundary in &mut self.boundaries[start..end] {
+				*boundary = Boundary::NoContent;
+			}
+
+			self.update_surrounding_boundaries(start, end);
+		}
+	}
+
+	/// Creates a view of the sentence whose characters keep their original case
+	/// if `case_sensitive` is `true`, and their confusables if `literal` is
+	/// `true`, for patterns with flags.
+	///
+	/// The view reads the same original characters as the sentence, and copies
+	/// its protected ranges and marks.
+	pub(crate) fn view(&self, case_sensitive: bool, literal: bool) -> SentenceView {
+		let mut contents: Vec<char> = Vec::with_capacity(self.contents.len());
+		let mut origins: Vec<(usize, usize)> = Vec::with_capacity(self.contents.len());
+		let mut normalizations: Vec<Normalization> = Vec::with_capacity(self.contents.len());
+		let mut groups: Vec<(usize, usize, usize, usize)> = Vec::new();
+
+		let mut start = 0usize;
+		while start < self.contents.len() {
+			let origin = self.origins[start];
+			let mut end = start + 1;
+			while end < self.contents.len() && self.origins[end] == origin {
+				end += 1;
+			}
+
+			// Read the original character again, unless it has no original characters,
+			// such as the separator of acrostics:
+			let view_start = contents.len();
+			if origin.0 == origin.1 {
+				contents.extend(&self.contents[start..end]);
+				normalizations.extend(&self.normalizations[start..end]);
+			} else {
+				let c = self.original[origin.0];
+				let c = compose_single_mark(c, &self.original[origin.0 + 1..origin.1]).unwrap_or(c);
+				let confusable = !literal && c.contains_confusables();
+				let replaced = if literal { c.to_string() } else { c.replace_confusables() };
+				let folded = if case_sensitive { replaced.clone() } else { replaced.to_lowercase() };
+				let normalization = Normalization { confusable, case_folded: folded != replaced };
+
+				contents.extend(folded.chars());
+				normalizations.resize(contents.len(), normalization);
+			}
+
+			origins.resize(contents.len(), origin);
+			groups.push((start, end, view_start, contents.len()));
+			start = end;
+		}
+
+		let group_of = |index: usize| groups.partition_point(|(_, end, _, _)| *end <= index);
+		let protected = self
+			.protected
+			.iter()
+			.map(|(start, end, kind)| (groups[group_of(*start)].2, groups[group_of(end - 1)].3, *kind))
+			.collect();
+
+		let mut sentence = Self {
+			spans: vec![(0usize, contents.len())],
+			boundaries: Self::read_boundaries(&contents),
+			contents,
+			original: self.original.clone(),
+			origins,
+			normalizations,
+			protected,
+			sparse: self.sparse,
+			patterns: Vec::new(),
+			metrics: OnceCell::new(),
+			identity: OnceCell::new(),
+		};
+		sentence.apply_protections();
+
+		// Copy every run of marked characters:
+		let mut index = 0usize;
+		while index < self.boundaries.len() {
+			if self.boundaries[index] != Boundary::Marked {
+				index += 1;
+				continue;
+			}
+
+			let start = index;
+			while index < self.boundaries.len() && self.boundaries[index] == Boundary::Marked {
+				index += 1;
+			}
+
+			sentence.mark(groups[group_of(start)].2, groups[group_of(index - 1)].3);
+		}
+
+		SentenceView { sentence, groups }
+	}
+
+	/// Reads the word boundaries of the sanitized contents of a sentence.
+	fn read_boundaries(contents: &[char]) -> Vec<Boundary> {
+		let mut boundaries: Vec<Boundary> = Vec::with_capacity(contents.len());
+
+		let mut chars = contents.iter().peekable();
+		while let Some(c) = chars.next() {
+			// If the character is a whitespace or control character, the boundary is no
+			// content.
+			if !c.is_alphanumeric() {
+				boundaries.push(Boundary::NoContent);
+				continue;
+			};
+
+			// Read the following characters until the end of `sentence` or until a
+			// whitespace or control character is found.
+			//
+			// The first character is always the start of a word, so the boundary is set to
+			// start. Then, we scan the following characters until a whitespace or control
+			// character is found.
+			let mut boundary = Boundary::Start;
+			while let Some(c) = chars.peek() {
+				if !c.is_alphanumeric() {
+					break;
+				}
+
+				// There is a word character, push the previous character's boundary, increase
+				// the word size, and set the boundary to `Word`.
+				boundaries.push(boundary);
+				boundary = Boundary::Word;
+				chars.next();
+			}
+
+			// Process the last character from the loop.
+			boundaries.push(if boundary == Boundary::Start {
+				// If the boundary is the start, the size is 1, and the character is a word,
+				// therefore the boundary is mixed.
+				Boundary::Mixed
+			} else {
+				// If the size is greater than 1, the boundary is a word.
+				Boundary::End
+			});
+		}
+
+		boundaries
+	}
+
+	/// Returns the ranges of `contents` of every token, the runs of word
+	/// characters outside the protected ranges, regardless of the marks.
+	pub(crate) fn tokens(&self) -> Vec<(usize, usize)> {
+		let mut tokens: Vec<(usize, usize)> = Vec::new();
+		let mut protected = self.protected.iter().peekable();
+
+		for (index, c) in self.contents.iter().enumerate() {
+			while protected.next_if(|(_, end, _)| *end <= index).is_some() {}
+			if !c.is_alphanumeric() || protected.peek().is_some_and(|(start, _, _)| *start <= index) {
+				continue;
+			}
+
+			match tokens.last_mut() {
+				Some((_, end)) if *end == index => *end = index + 1,
+				_ => tokens.push((index, index + 1)),
+			}
+		}
+
+		tokens
+	}
+
+	/// Returns the ranges of characters in `original` that were marked, sorted
+	/// and without overlaps.
+	pub(crate) fn marked_ranges(&self) -> Vec<(usize, usize)> {
+		let mut ranges: Vec<(usize, usize)> = Vec::new();
+		let mut previous_marked = false;
+
+		for (index, boundary) in self.boundaries.iter().enumerate() {
+			let marked = *boundary == Boundary::Marked;
+			if !marked {
+				previous_marked = false;
+				continue;
+			}
+
+			let (start, end) = self.origins[index];
+			if start == end {
+				continue;
+			}
+
+			match ranges.last_mut() {
+				// Extend the last range if this character continues the same run, or if it
+				// shares its original character with the last range:
+				Some((_, last_end))
+					if (previous_marked && (!self.sparse || start == *last_end)) || start < *last_end =>
+				{
+					*last_end = end.max(*last_end)
+				}
+				_ => ranges.push((start, end)),
+			}
+
+			previous_marked = true;
+		}
+
+		ranges
+	}
+
+	/// Returns the byte and UTF-16 offsets of every character in `original`,
+	/// plus one extra entry for the end of the string.
+	pub(crate) fn original_offsets(&self) -> Vec<(usize, usize)> {
+		let mut offsets: Vec<(usize, usize)> = Vec::with_capacity(self.original.len() + 1);
+		let (mut byte, mut utf16) = (0usize, 0usize);
+
+		for c in &self.original {
+			offsets.push((byte, utf16));
+			byte += c.len_utf8();
+			utf16 += c.len_utf16();
+		}
+
+		offsets.push((byte, utf16));
+		offsets
+	}
+
 	/// Updates the state of the sentence to mark the start and end of a word.
-	pub(crate) fn mark(&mut self, start: usize, end: usize) {
+	///
+	/// Returns `false` if the word was already marked, in which case the
+	/// sentence is left untouched.
+	pub(crate) fn mark(&mut self, start: usize, end: usize) -> bool {
 		debug_assert!(start < end, "start must be less than end");
 		debug_assert!(end <= self.contents.len(), "end must be less than or equal to the length of the sentence");
 
-		let (start, end) = self.mark_update_spans(start, end);
-		self.mark_update_boundaries(start, end);
+		match self.mark_update_spans(start, end) {
+			Some((start, end)) => {
+				self.mark_update_boundaries(start, end);
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Marks a word match, pushing it to `matches` unless it was already
+	/// marked by a previous match, in which case `false` is returned.
+	///
+	/// The match is flagged as `confusable` if any of its characters was
+	/// replaced from a confusable.
+	pub(crate) fn mark_match(&mut self, found: WordMatch, matches: &mut Vec<WordMatch>) -> bool {
+		let marked = self.mark(found.start, found.end);
+		if marked {
+			let confusable = self.normalizations[found.start..found.end].iter().any(|n| n.confusable);
+			matches.push(WordMatch { confusable, ..found });
+		}
+
+		marked
 	}
 
 	/// Updates the `spans` vector to mark the start and end of a word. If the
-	/// word is surrounded by non-word characters, the span is expanded to
-	/// include the surrounding characters.
-	///
-	/// If the expanded span is in contact with the previous or next span, the
-	/// spans are merged into a single span.
-	fn mark_update_spans(&mut self, start: usize, end: usize) -> (usize, usize) {
-		// 1. Find the span that contains the word.
-		let span_index = self.spans.iter().position(|(s, e)| *s <= start && end <= *e).unwrap();
+	/// word is surrounded by word characters, the marked slice is expanded to
+	/// include them, up to the surrounding non-word characters.
+	///
+	/// The marked slice is then removed from the span that contains it, which
+	/// is split into the slices before and after it, if any.
+	///
+	/// Returns the marked slice, or `None` if `start` was already marked.
+	fn mark_update_spans(&mut self, start: usize, end: usize) -> Option<(usize, usize)> {
+		// 1. Find the span that contains the word, the word cannot extend past it.
+		let span_index = self.spans.iter().position(|(s, e)| *s <= start && start < *e)?;
 		let (current_start, current_end) = self.spans[span_index];
+		let end = end.min(current_end);
 
-		// 2. Expand the span to include non-word characters surrounding the matched
-		//    slice within
-		// the current span.
+		// 2. Expand the matched slice up to the non-word characters surrounding it
+		//    within the current span.
 		let start = self
 			.boundaries
 			.iter()
 			.enumerate()
 			.take(start)
 			.rposition(|(index, boundary)| index >= current_start && *boundary == Boundary::NoContent)
-			.map_or(0, |i| i + 1);
+			.map_or(current_start, |i| i + 1);
 		let end = self
 			.boundaries
 			.iter()
 			.enumerate()
 			.skip(end)
 			.position(|(index, boundary)| index < current_end && *boundary == Boundary::NoContent)
-			.map_or(self.contents.len(), |i| end + i);
-
-		// 3. Merge with surrounding spans if applicable:
-		// - Merge with the previous span if the end of the previous span is the start
-		//   of the word.
-		// - Merge with the next span if the start of the next span is the end of the
-		//   word.
-		//
-		// If the span merges with the previous and next spans, all 3 are removed from
-		// the `spans` vector and a new span is added to the `spans` vector.
-		match (start == current_start && start != 0usize, end == current_end && end != self.contents.len()) {
-			(true, true) => self.merge_span_both(span_index),
-			(true, false) => self.merge_span_previous(span_index),
-			(false, true) => self.merge_span_next(span_index),
-			(false, false) => self.spans[span_index] = (start, end),
-		};
+			.map_or(current_end, |i| end + i);
 
-		(start, end)
-	}
-
-	/// Merges the current span with the previous span.
-	fn merge_span_previous(&mut self, index: usize) {
-		let (_, end) = self.spans.remove(index);
-		let (previous_start, _) = self.spans.remove(index - 1);
-		self.spans.insert(index - 1, (previous_start, end));
-	}
-
-	/// Merges the current span with the next span.
-	fn merge_span_next(&mut self, index: usize) {
-		let (start, _) = self.spans.remove(index);
-		let (_, next_end) = self.spans.remove(index);
-		self.spans.insert(index, (start, next_end));
-	}
+		// 3. Split the span, removing the marked slice from it. The remaining slices
+		//    before and after the marked slice are kept only if they are not empty.
+		self.spans.remove(span_index);
+		if end < current_end {
+			self.spans.insert(span_index, (end, current_end));
+		}
+		if current_start < start {
+			self.spans.insert(span_index, (current_start, start));
+		}
 
-	/// Merges the current span with the previous and next spans.
-	fn merge_span_both(&mut self, index: usize) {
-		let (_, next_end) = self.spans.remove(index + 1);
-		self.spans.remove(index);
-		let (previous_start, _) = self.spans.remove(index - 1);
-		self.spans.insert(index - 1, (previous_start, next_end));
+		Some((start, end))
 	}
 
 	/// Updates the `boundaries` vector to set all characters within `start` and
@@ -322,6 +1155,12 @@ impl Sentence {
 		}
 
 		// 2. Update the boundaries surrounding the marked characters.
+		self.update_surrounding_boundaries(start, end);
+	}
+
+	/// Updates the boundaries surrounding the characters within `start` and
+	/// `end`, which are no longer part of the words around them.
+	fn update_surrounding_boundaries(&mut self, start: usize, end: usize) {
 		// 2.1. Update the boundary before the marked characters if applicable.
 		if start > 0 {
 			self.boundaries[start - 1] = match self.boundaries[start - 1] {
@@ -352,6 +1191,39 @@ impl Sentence {
 	}
 }
 
+/// Whether or not a character is a combining mark that is folded into the
+/// character before it, such as the marks stacked in zalgo text, unless it is
+/// a single accent, see [`compose_single_mark`].
+///
+/// Only the marks of the blocks of combining diacritical marks, which are
+/// shared by all scripts, are folded. The marks of other blocks, such as the
+/// vowel signs of Devanagari, are part of the words they are in.
+pub(crate) fn is_foldable_mark(c: char) -> bool {
+	matches!(
+		c,
+		'\u{0300}'..='\u{036F}'
+			| '\u{0483}'..='\u{0489}'
+			| '\u{1AB0}'..='\u{1AFF}'
+			| '\u{1DC0}'..='\u{1DFF}'
+			| '\u{20D0}'..='\u{20FF}'
+			| '\u{FE20}'..='\u{FE2F}'
+	)
+}
+
+/// Composes a character with the combining mark after it, such as an `"e"`
+/// followed by a combining acute accent into `"é"`, if it is the only mark
+/// after the character, so a decomposed accent is read like a precomposed
+/// one. Marks that do not compose with the character, and stacks of marks,
+/// are folded instead.
+pub(crate) fn compose_single_mark(c: char, following: &[char]) -> Option<char> {
+	match following {
+		[mark, rest @ ..] if is_foldable_mark(*mark) && !rest.first().is_some_and(|c| is_foldable_mark(*c)) => {
+			compose(c, *mark)
+		}
+		_ => None,
+	}
+}
+
 impl fmt::Display for Sentence {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(f, "{}", self.contents.iter().collect::<String>())
@@ -0,0 +1,216 @@
+use crate::{
+	confusables::Confusable,
+	sentence::{is_foldable_mark, Sentence},
+	word::Word,
+	word_list::WordList,
+};
+
+/// The default amount of characters of already scanned text that is kept to
+/// detect matches that span across chunks.
+const DEFAULT_OVERLAP: u32 = 64;
+
+#[derive(Clone)]
+#[napi(object)]
+pub struct StreamScannerOptions {
+	/// The amount of characters of already scanned text that is kept to detect
+	/// matches that span across chunks, defaults to `64`.
+	pub overlap: Option<u32>,
+}
+
+/// A match found by a `StreamScanner`.
+#[derive(Clone)]
+#[napi(object)]
+pub struct StreamMatch {
+	/// The index of the word of the list that matched.
+	pub word: u32,
+	/// The UTF-16 offset of the first matched character within the stream.
+	pub start: u32,
+	/// The UTF-16 offset after the last matched character within the stream.
+	pub end: u32,
+}
+
+/// A scanner that matches a `WordList` against text that is received in
+/// chunks, without building a `Sentence` for the whole text.
+///
+/// Chunks are only scanned up to their last non-word character, the trailing
+/// word is kept until the next chunk (or `finish`) tells where it ends. A
+/// small window of already scanned text is also kept, so words split across
+/// chunks by non-word characters (such as `"b ar"`) are still matched.
+///
+/// A trailing word is kept for at most as many characters as the longest
+/// pattern of the list, longer words are scanned as they arrive, so a stream
+/// without non-word characters does not grow the scanner without bound.
+#[napi]
+pub struct StreamScanner {
+	list: WordList,
+	/// The maximum amount of characters kept in `context`.
+	overlap: usize,
+	/// The tail of the already scanned text, kept as the start of the next
+	/// scanned slice.
+	context: Vec<char>,
+	/// The text that was received but not scanned yet, as its last word might
+	/// continue in the next chunk.
+	pending: Vec<char>,
+	/// The maximum amount of characters kept in `pending`, the length of the
+	/// longest pattern of the list.
+	limit: usize,
+	/// Whether or not `context` starts in the middle of a word, so its first
+	/// character is not the start of a word.
+	continued: bool,
+	/// The UTF-16 offset of the start of `context` within the stream.
+	offset: usize,
+	/// The UTF-16 offset after the last reported match, matches that start
+	/// before it are not reported again.
+	reported: usize,
+}
+
+#[napi]
+impl StreamScanner {
+	#[napi(constructor)]
+	pub fn new(list: &WordList, options: Option<StreamScannerOptions>) -> Self {
+		let overlap = options.and_then(|options| options.overlap).unwrap_or(DEFAULT_OVERLAP) as usize;
+		let limit = list.words.iter().map(Word::pattern_length).max().unwrap_or(0).max(1);
+		StreamScanner {
+			list: list.clone(),
+			overlap,
+			context: Vec::new(),
+			pending: Vec::new(),
+			limit,
+			continued: false,
+			offset: 0,
+			reported: 0,
+		}
+	}
+
+	/// Pushes a chunk of text into the scanner, returning the matches that
+	/// could be completed with it.
+	///
+	/// @param chunk - The next chunk of text of the stream.
+	///
+	/// @returns The matches found, with their offsets relative to the start of
+	/// the stream.
+	#[napi]
+	pub fn push(&mut self, chunk: String) -> Vec<StreamMatch> {
+		self.pending.extend(chunk.chars());
+
+		// Only scan up to the last non-word character, the rest may be completed by
+		// the next chunk:
+		match self.pending.iter().rposition(|c| !is_word_character(*c)) {
+			Some(index) => {
+				let rest = self.pending.split_off(index + 1);
+				let ready = std::mem::replace(&mut self.pending, rest);
+				self.scan(ready)
+			}
+			// The trailing word is already longer than any pattern, scan it up to the
+			// last `limit` characters and the marks folded into them, which are kept to
+			// tell where it ends:
+			None => {
+				let mut characters = 0usize;
+				let kept = self.pending.iter().rposition(|c| {
+					characters += usize::from(!is_foldable_mark(*c));
+					characters == self.limit
+				});
+
+				match kept {
+					Some(index) if index > 0 => {
+						let rest = self.pending.split_off(index);
+						let ready = std::mem::replace(&mut self.pending, rest);
+						self.scan(ready)
+					}
+					_ => Vec::new(),
+				}
+			}
+		}
+	}
+
+	/// Scans the remaining text of the stream, returning the last matches.
+	/// The scanner can be reused for a new stream afterwards.
+	///
+	/// @returns The matches found, with their offsets relative to the start of
+	/// the stream.
+	#[napi]
+	pub fn finish(&mut self) -> Vec<StreamMatch> {
+		let ready = std::mem::take(&mut self.pending);
+		let matches = self.scan(ready);
+
+		self.context.clear();
+		self.continued = false;
+		self.offset = 0;
+		self.reported = 0;
+		matches
+	}
+
+	/// Scans `context` followed by `ready`, reporting the matches that end
+	/// within `ready`, as the ones ending within `context` were reported by a
+	/// previous scan.
+	///
+	/// `pending` is scanned too, so a word cut at the end of `ready` is not
+	/// read as ending there, but matches ending within it are not reported yet.
+	fn scan(&mut self, ready: Vec<char>) -> Vec<StreamMatch> {
+		let context_length = self.context.len();
+		let mut text = std::mem::take(&mut self.context);
+		text.extend(ready);
+
+		// The UTF-16 offset of every character of the text, relative to the stream:
+		let mut offsets = Vec::with_capacity(text.len() + 1);
+		let mut offset = self.offset;
+		for c in &text {
+			offsets.push(offset);
+			offset += c.len_utf16();
+		}
+		offsets.push(offset);
+
+		let mut sentence = Sentence::new(text.iter().chain(&self.pending).collect());
+		let mut matches = Vec::new();
+		for (index, word) in self.list.words.iter().enumerate() {
+			for found in word.find(&mut sentence) {
+				let start = sentence.origins[found.start].0;
+				let end = sentence.origins[found.end - 1].1;
+				if end <= context_length || end > text.len() || offsets[start] < self.reported {
+					continue;
+				}
+
+				// The context starts within a word that was cut by a previous scan:
+				if start == 0 && self.continued && word.bound_left {
+					continue;
+				}
+
+				matches.push(StreamMatch {
+					word: index as u32,
+					start: offsets[start] as u32,
+					end: offsets[end] as u32,
+				});
+			}
+		}
+
+		matches.sort_by_key(|found| found.start);
+		if let Some(last) = matches.iter().map(|found| found.end).max() {
+			self.reported = self.reported.max(last as usize);
+		}
+
+		// Keep the tail of the text as context for the next scan, starting at a word
+		// boundary so no word is cut in half, unless the text ends with a word that
+		// is longer than the context:
+		let mut start = text.len().saturating_sub(self.overlap);
+		while start > 0 && start < text.len() && is_word_character(text[start - 1]) {
+			start += 1;
+		}
+		if start == text.len() && !self.pending.is_empty() {
+			start = text.len().saturating_sub(self.overlap);
+			while start > 0 && start < text.len() && is_foldable_mark(text[start]) {
+				start -= 1;
+			}
+		}
+
+		self.continued = if start == 0 { self.continued } else { is_word_character(text[start - 1]) };
+		self.offset = offsets[start];
+		self.context = text.split_off(start);
+		matches
+	}
+}
+
+/// Whether or not a character is read as part of a word by `Sentence`,
+/// including the combining marks folded into the character before them.
+fn is_word_character(c: char) -> bool {
+	is_foldable_mark(c) || c.replace_confusables().chars().any(char::is_alphanumeric)
+}
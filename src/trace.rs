@@ -0,0 +1,181 @@
+use crate::{
+	word::{MatchKind, WordMatch},
+	word_part::WordPart,
+};
+
+/// The outcome of reading a character while matching a candidate.
+#[napi]
+#[derive(PartialEq)]
+pub enum TraceStepKind {
+	/// The character matched the current part of the word, moving to the next
+	/// one.
+	Matched,
+	/// The character did not match the current part of the word, but matched
+	/// the previous one, such as the second `"a"` in `"baar"` for `"bar"`.
+	Repeated,
+	/// The character is a non-word character, which is skipped.
+	Skipped,
+	/// The character matched neither the current nor the previous part of the
+	/// word, abandoning the candidate unless it already matched.
+	Mismatched,
+}
+
+/// The reason why a candidate was abandoned.
+#[napi]
+#[derive(PartialEq)]
+pub enum AbandonReason {
+	/// A character matched neither the current nor the previous part of the
+	/// word.
+	Mismatch,
+	/// The span ended before the word could be matched.
+	EndOfSpan,
+	/// The candidate matched, but it overlaps a previous match.
+	AlreadyMarked,
+}
+
+/// A character read while matching a candidate.
+#[derive(Clone)]
+#[napi(object)]
+pub struct TraceStep {
+	/// The index of the character within the normalized text.
+	pub index: u32,
+	/// The character that was read.
+	pub character: String,
+	/// The outcome of reading the character.
+	pub kind: TraceStepKind,
+	/// The index of the part of the word that matched the character, if any.
+	pub part: Option<u32>,
+	/// The part of the word that matched the character, as written in the
+	/// pattern, if any.
+	pub pattern: Option<String>,
+}
+
+/// A position of the normalized text where the first part of the word
+/// matched, from which the rest of the word was tried.
+#[derive(Clone)]
+#[napi(object)]
+pub struct TraceCandidate {
+	/// The index of the character where the candidate started. Words that are
+	/// only bound to the right (such as `"**bar"`) are read backwards, starting
+	/// from their last character.
+	pub start: u32,
+	/// The index of the first matched character, if the candidate matched.
+	pub match_start: Option<u32>,
+	/// The index after the last matched character, if the candidate matched.
+	pub match_end: Option<u32>,
+	/// The amount of edits between the matched characters and the word, if
+	/// the candidate matched. Candidates tried with edits start at the first
+	/// character of a word of the sentence, and have no steps.
+	pub distance: Option<u32>,
+	/// How the candidate was matched, if it matched.
+	pub kind: Option<MatchKind>,
+	/// Whether or not the candidate matched and was marked.
+	pub matched: bool,
+	/// The reason why the candidate was abandoned, if it did not match.
+	pub reason: Option<AbandonReason>,
+	/// The characters read while matching the candidate.
+	pub steps: Vec<TraceStep>,
+}
+
+/// A span of the sentence the word was matched against.
+#[derive(Clone)]
+#[napi(object)]
+pub struct TraceSpan {
+	/// The index of the first character of the span.
+	pub start: u32,
+	/// The index after the last character of the span.
+	pub end: u32,
+	/// Whether or not the span was skipped because it is shorter than the
+	/// word.
+	pub skipped: bool,
+	/// The candidates that were tried within the span.
+	pub candidates: Vec<TraceCandidate>,
+}
+
+/// A trace of how a word was matched against a text, see `Word#explain`.
+#[derive(Clone)]
+#[napi(object)]
+pub struct MatchTrace {
+	/// The normalized text the word was matched against.
+	pub normalized: String,
+	/// The word as it was parsed, see `Word#toString`.
+	pub pattern: String,
+	/// Whether or not the word matched the text.
+	pub matched: bool,
+	/// The spans of the sentence that were tried.
+	pub spans: Vec<TraceSpan>,
+}
+
+/// Receives the events of the `Word` matchers. The implementation for `()`
+/// does nothing, and is used when no trace is requested.
+pub(crate) trait Tracer {
+	/// A candidate starts at `start`.
+	fn candidate(&mut self, start: usize);
+	/// A character of the current candidate was read.
+	fn step(&mut self, index: usize, character: char, kind: TraceStepKind, part: Option<(usize, &WordPart)>);
+	/// The current candidate matched, `marked` is `false` if it overlapped a
+	/// previous match.
+	fn complete(&mut self, found: &WordMatch, marked: bool);
+	/// The current candidate was abandoned.
+	fn abandon(&mut self, reason: AbandonReason);
+}
+
+impl Tracer for () {
+	#[inline(always)]
+	fn candidate(&mut self, _start: usize) {}
+
+	#[inline(always)]
+	fn step(&mut self, _index: usize, _character: char, _kind: TraceStepKind, _part: Option<(usize, &WordPart)>) {}
+
+	#[inline(always)]
+	fn complete(&mut self, _found: &WordMatch, _marked: bool) {}
+
+	#[inline(always)]
+	fn abandon(&mut self, _reason: AbandonReason) {}
+}
+
+impl Tracer for TraceSpan {
+	fn candidate(&mut self, start: usize) {
+		self.candidates.push(TraceCandidate {
+			start: start as u32,
+			match_start: None,
+			match_end: None,
+			distance: None,
+			kind: None,
+			matched: false,
+			reason: None,
+			steps: Vec::new(),
+		});
+	}
+
+	fn step(&mut self, index: usize, character: char, kind: TraceStepKind, part: Option<(usize, &WordPart)>) {
+		if let Some(candidate) = self.candidates.last_mut() {
+			candidate.steps.push(TraceStep {
+				index: index as u32,
+				character: character.to_string(),
+				kind,
+				part: part.map(|(index, _)| index as u32),
+				pattern: part.map(|(_, part)| part.to_string()),
+			});
+		}
+	}
+
+	fn complete(&mut self, found: &WordMatch, marked: bool) {
+		if let Some(candidate) = self.candidates.last_mut() {
+			candidate.match_start = Some(found.start as u32);
+			candidate.match_end = Some(found.end as u32);
+			candidate.distance = Some(found.distance as u32);
+			candidate.kind = Some(found.kind);
+			candidate.matched = marked;
+			if !marked {
+				candidate.reason = Some(AbandonReason::AlreadyMarked);
+			}
+		}
+	}
+
+	fn abandon(&mut self, reason: AbandonReason) {
+		if let Some(candidate) = self.candidates.last_mut() {
+			candidate.reason = Some(reason);
+		}
+	}
+}
@@ -1,18 +1,42 @@
 use std::char;
 
-use napi::{Error, Status};
+use napi::{Env, Error};
 
 use crate::{
 	confusables::Confusable,
 	constants::*,
-	sentence::{Boundary, Sentence},
+	error::ParseError,
+	fuzzy::find_fuzzy,
+	phonetic::{phonetic_key, phonetic_key_of_parts},
+	sentence::{compose_single_mark, is_foldable_mark, Boundary, Sentence},
+	trace::{AbandonReason, MatchTrace, TraceSpan, TraceStepKind, Tracer},
 	word_part::*,
 };
 
+/// How a `WordMatch` was found.
 #[napi]
+#[derive(Debug, PartialEq)]
+pub enum MatchKind {
+	/// The characters matched the pattern of the word.
+	Literal,
+	/// The characters matched the pattern of the word with some edits, see
+	/// `WordOptions.maxDistance`.
+	Fuzzy,
+	/// The characters sound like the word, see `WordOptions.phonetic`.
+	Phonetic,
+	/// The characters matched the pattern of the word when read backwards,
+	/// see `WordOptions.reversed`.
+	Reversed,
+}
+
+#[napi]
+#[derive(Clone, Copy)]
 pub struct WordMatch {
 	pub(crate) start: usize,
 	pub(crate) end: usize,
+	pub(crate) distance: usize,
+	pub(crate) kind: MatchKind,
+	pub(crate) confusable: bool,
 }
 
 #[napi]
@@ -26,6 +50,80 @@ impl WordMatch {
 	pub fn get_end(&self) -> u32 {
 		self.end.try_into().unwrap()
 	}
+
+	/// The amount of edits between the matched characters and the word, `0`
+	/// for exact matches, see `WordOptions.maxDistance`.
+	#[napi(getter, enumerable = true, js_name = "distance")]
+	pub fn get_distance(&self) -> u32 {
+		self.distance.try_into().unwrap()
+	}
+
+	/// How the match was found.
+	#[napi(getter, enumerable = true, js_name = "kind")]
+	pub fn get_kind(&self) -> MatchKind {
+		self.kind
+	}
+
+	/// Whether or not any of the matched characters was replaced from a
+	/// confusable, such as a Cyrillic `"а"` in place of a Latin `"a"`, see
+	/// `Sentence#provenance`.
+	#[napi(getter, enumerable = true, js_name = "confusable")]
+	pub fn get_confusable(&self) -> bool {
+		self.confusable
+	}
+}
+
+impl WordMatch {
+	/// Creates an exact match of the characters from `start` to `end`.
+	pub(crate) fn literal(start: usize, end: usize) -> Self {
+		WordMatch { start, end, distance: 0, kind: MatchKind::Literal, confusable: false }
+	}
+}
+
+#[derive(Clone, Default)]
+#[napi(object)]
+pub struct WordOptions {
+	/// The maximum amount of substitutions, insertions, deletions, or swaps of
+	/// adjacent characters allowed within a word of the sentence, defaults to
+	/// `0`.
+	///
+	/// Words are allowed at most one edit for every three characters, so words
+	/// shorter than three characters are never matched with edits.
+	pub max_distance: Option<u32>,
+	/// Whether or not the words of the sentence that sound like the word are
+	/// matched as well, comparing their English phonetic keys, defaults to
+	/// `false`.
+	///
+	/// Phonetic keys keep the consonants and the vowels of a word, reading
+	/// `"e"` and `"i"` alike, so `"phuk"` sounds like `"fuck"`, but `"fake"`
+	/// does not.
+	pub phonetic: Option<bool>,
+	/// Whether or not the words of the sentence written backwards (such as
+	/// `"kcuf"`) are matched as well, defaults to `false`.
+	///
+	/// This also catches words displayed backwards with right-to-left
+	/// override characters, as they are stored in reverse.
+	pub reversed: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[napi(object)]
+pub struct FromPartsOptions {
+	/// Whether or not the word must match the left boundary of the sentence,
+	/// defaults to `true`.
+	pub bound_left: Option<bool>,
+	/// Whether or not the word must match the right boundary of the sentence,
+	/// defaults to `true`.
+	pub bound_right: Option<bool>,
+	/// The maximum amount of edits allowed within a word of the sentence,
+	/// defaults to `0`, see `WordOptions.maxDistance`.
+	pub max_distance: Option<u32>,
+	/// Whether or not the words that sound like the word are matched as well,
+	/// defaults to `false`, see `WordOptions.phonetic`.
+	pub phonetic: Option<bool>,
+	/// Whether or not the words written backwards are matched as well,
+	/// defaults to `false`, see `WordOptions.reversed`.
+	pub reversed: Option<bool>,
 }
 
 /// The `Word` struct represents a word that can be matched against a
@@ -45,22 +143,100 @@ pub struct Word {
 	pub bound_left: bool,
 	/// If `true`, the word must match the right boundary of the sentence.
 	pub bound_right: bool,
+	/// The maximum amount of edits allowed within a word of the sentence. If
+	/// greater than `0`, the words of the sentence that do not match exactly
+	/// are compared with the word allowing up to this many edits.
+	pub max_distance: u32,
+	/// If `true`, the words of the sentence that do not match exactly are
+	/// compared with the word by their phonetic keys.
+	pub phonetic: bool,
+	/// If `true`, the words of the sentence that do not match exactly are
+	/// read backwards and matched against the word.
+	pub reversed: bool,
+	/// If `true`, the word is matched against the original case of the
+	/// sentence, set with the `"(?c)"` pattern flag.
+	pub case_sensitive: bool,
+	/// If `true`, the word is matched against the sentence without replacing
+	/// its confusables, set with the `"(?l)"` pattern flag.
+	pub literal: bool,
+	/// The words that must match the tokens following the one matched by this
+	/// word, for phrase patterns such as `"kill yourself"`.
+	phrase: Vec<Word>,
 }
 
 #[napi]
 impl Word {
+	/// @param word - The pattern of the word.
+	/// @param options - The options of the word.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const word = new Word("fuck", { maxDistance: 1 });
+	/// word.find(new Sentence("what the fcuk"));
+	/// // ⇒ [{ start: 9, end: 13, distance: 1, kind: MatchKind.Fuzzy }]
+	/// ```
+	///
+	/// @remarks
+	///
+	/// If the pattern cannot be parsed, the thrown error has a `code` property
+	/// with the `ParseErrorCode` of the error, and an `index` property with the
+	/// UTF-16 offset of the offending character within the pattern.
 	#[napi(constructor)]
-	pub fn new(word: String) -> Result<Self, Error> {
-		let word = word.replace_confusables().to_lowercase();
-		let mut chars = word.chars().peekable();
+	pub fn new(env: Env, word: String, options: Option<WordOptions>) -> Result<Self, Error> {
+		let options = options.unwrap_or_default();
+		let word = Self::parse(&word).map_err(|error| error.into_napi_error(&env))?;
+		Ok(word.with_options(
+			options.max_distance.unwrap_or(0),
+			options.phonetic.unwrap_or(false),
+			options.reversed.unwrap_or(false),
+		))
+	}
+
+	/// Parses a pattern into a `Word`.
+	///
+	/// The pattern is sanitized like a `Sentence`, and the positions of the
+	/// errors refer to the characters of the original pattern. Whitespace
+	/// outside groups splits the pattern into a phrase, whose words must match
+	/// consecutive tokens of the sentence.
+	///
+	/// The pattern may start with flags, `"(?c)"` to match the original case of
+	/// the sentence, `"(?l)"` to keep its confusables, or both as `"(?cl)"`.
+	pub fn parse(word: &str) -> Result<Self, ParseError> {
+		let (flags, pattern) = Self::parse_flags(word)?;
+		let (case_sensitive, literal) = (flags.contains(&FLAG_CASE_SENSITIVE), flags.contains(&FLAG_LITERAL));
+
+		// Sanitize every character of the pattern, keeping the UTF-16 offset of the
+		// character it comes from, so errors can point to the original pattern:
+		let mut offset = word.len() - pattern.len();
+		let mut sanitized: Vec<(usize, char)> = Vec::with_capacity(pattern.len());
+		let characters: Vec<char> = pattern.chars().collect();
+		for (index, &c) in characters.iter().enumerate() {
+			if !is_foldable_mark(c) {
+				let c = compose_single_mark(c, &characters[index + 1..]).unwrap_or(c);
+				let replaced = if literal { c.to_string() } else { c.replace_confusables() };
+				let folded = if case_sensitive { replaced } else { replaced.to_lowercase() };
+				sanitized.extend(folded.chars().map(|c| (offset, c)));
+			}
+			offset += c.len_utf16();
+		}
 
-		let mut parts: Vec<WordPart> = Vec::new();
-		while let Some(c) = chars.next() {
+		let mut chars = sanitized.into_iter().peekable();
+		let mut words: Vec<Vec<(usize, WordPart)>> = Vec::new();
+		let mut parts: Vec<(usize, WordPart)> = Vec::new();
+		while let Some((index, c)) = chars.next() {
 			let part = match c {
+				// Found whitespace, the end of a word of the phrase:
+				c if c.is_whitespace() => {
+					if !parts.is_empty() {
+						words.push(std::mem::take(&mut parts));
+					}
+
+					continue;
+				}
 				ASTERISK => {
-					if chars.peek() == Some(&ASTERISK) {
+					if chars.next_if(|(_, c)| *c == ASTERISK).is_some() {
 						// Found '**':
-						chars.next();
 						WordPart::AnyWildcard
 					} else {
 						// Found '*':
@@ -73,24 +249,24 @@ impl Word {
 					loop {
 						match chars.next() {
 							// If ']' is found, it is the end of the Group
-							Some(GROUP_END) => {
+							Some((_, GROUP_END)) => {
 								break;
 							}
 							// If '\' is found, it is an escape character, read the next character
-							Some(ESCAPE) => {
-								if let Some(c) = chars.next() {
+							Some((_, ESCAPE)) => {
+								if let Some((_, c)) = chars.next() {
 									group.push(c);
 								}
 							}
 							// If a character is found, add it to the Group
-							Some(c) => {
+							Some((_, c)) => {
 								if !group.contains(&c) {
 									group.push(c);
 								}
 							}
 							// If the end of the word is reached, return an error
 							None => {
-								return Err(Error::new(Status::GenericFailure, "Unterminated character group"));
+								return Err(ParseError::UnterminatedGroup { index });
 							}
 						}
 					}
@@ -108,44 +284,156 @@ impl Word {
 				}
 				ESCAPE => {
 					// Found '\'
-					if let Some(c) = chars.next() {
-						WordPart::Single(c)
-					} else {
-						return Err(Error::new(
-							Status::GenericFailure,
-							"Escape character cannot be at the end of the word",
-						));
+					match chars.next() {
+						// Whitespace only separates the words of a phrase, it is never matched:
+						Some((_, c)) if c.is_whitespace() => return Err(ParseError::EscapedWhitespace { index }),
+						Some((_, c)) => WordPart::Single(c),
+						None => return Err(ParseError::TrailingEscape { index }),
 					}
 				}
 				_ => WordPart::Single(c),
 			};
 
-			parts.push(part);
+			parts.push((index, part));
+		}
+
+		if !parts.is_empty() || words.is_empty() {
+			words.push(parts);
 		}
 
+		let mut words = words.into_iter().map(Self::from_word_parts).collect::<Result<Vec<_>, _>>()?;
+		let first = words.remove(0);
+		Ok(Word { case_sensitive, literal, phrase: words, ..first })
+	}
+
+	/// Reads the flags at the start of a pattern, such as `"(?cl)"`, returning
+	/// them along with the rest of the pattern.
+	fn parse_flags(word: &str) -> Result<(Vec<char>, &str), ParseError> {
+		let Some(rest) = word.strip_prefix(FLAGS_START) else {
+			return Ok((Vec::new(), word));
+		};
+
+		let Some((flags, pattern)) = rest.split_once(FLAGS_END) else {
+			return Err(ParseError::UnterminatedFlags { index: 0 });
+		};
+
+		if flags.is_empty() {
+			return Err(ParseError::EmptyFlags { index: 0 });
+		}
+
+		let mut offset = FLAGS_START.len();
+		for c in flags.chars() {
+			if c != FLAG_CASE_SENSITIVE && c != FLAG_LITERAL {
+				return Err(ParseError::UnknownFlag { index: offset });
+			}
+			offset += c.len_utf16();
+		}
+
+		Ok((flags.chars().collect(), pattern))
+	}
+
+	/// Builds a `Word` from its parts, reading leading and trailing any
+	/// wildcards as unbound boundaries. Every part is paired with the position
+	/// it was read from, which is used for errors.
+	fn from_word_parts(mut parts: Vec<(usize, WordPart)>) -> Result<Self, ParseError> {
 		if parts.is_empty() {
-			return Err(Error::new(Status::GenericFailure, "The word cannot be empty"));
+			return Err(ParseError::EmptyPattern);
 		}
 
-		let bound_left = parts.first().unwrap() != &WordPart::AnyWildcard;
+		let (first_index, first) = parts.first().unwrap();
+		let bound_left = first != &WordPart::AnyWildcard;
 		if !bound_left {
 			if parts.len() == 1 {
-				return Err(Error::new(Status::GenericFailure, "Wildcards cannot be the only character in the word"));
+				return Err(ParseError::WildcardOnly { index: *first_index });
 			}
 
 			parts.remove(0);
 		}
 
-		let bound_right = parts.last().unwrap() != &WordPart::AnyWildcard;
+		let (last_index, last) = parts.last().unwrap();
+		let bound_right = last != &WordPart::AnyWildcard;
 		if !bound_right {
 			if parts.len() == 1 {
-				return Err(Error::new(Status::GenericFailure, "Wildcards cannot be the only character in the word"));
+				return Err(ParseError::WildcardOnly { index: *last_index });
 			}
 
 			parts.pop();
 		}
 
-		Ok(Word { parts, bound_left, bound_right })
+		let parts = parts.into_iter().map(|(_, part)| part).collect();
+		Ok(Word {
+			parts,
+			bound_left,
+			bound_right,
+			max_distance: 0,
+			phonetic: false,
+			reversed: false,
+			case_sensitive: false,
+			literal: false,
+			phrase: Vec::new(),
+		})
+	}
+
+	/// Builds a `Word` from a list of parts, without writing its pattern.
+	///
+	/// @param parts - The parts of the word.
+	/// @param options - The boundaries of the word, both default to `true`.
+	///
+	/// @remarks
+	///
+	/// Like in patterns, leading and trailing `anyWildcard` parts unbind the
+	/// respective boundary of the word. The `index` of the errors thrown by
+	/// this method is the index of the offending part.
+	#[napi(factory)]
+	pub fn from_parts(env: Env, parts: Vec<WordPartObject>, options: Option<FromPartsOptions>) -> Result<Self, Error> {
+		let options = options.unwrap_or_default();
+		let length = parts.len();
+
+		let mut word_parts: Vec<(usize, WordPart)> = Vec::new();
+		if options.bound_left == Some(false) {
+			word_parts.push((0, WordPart::AnyWildcard));
+		}
+
+		for (index, part) in parts.into_iter().enumerate() {
+			match WordPart::from_object(part) {
+				Ok(parts) => word_parts.extend(parts.into_iter().map(|part| (index, part))),
+				Err(reason) => return Err(ParseError::InvalidPart { index, reason }.into_napi_error(&env)),
+			}
+		}
+
+		if options.bound_right == Some(false) {
+			word_parts.push((length, WordPart::AnyWildcard));
+		}
+
+		let word = Self::from_word_parts(word_parts).map_err(|error| error.into_napi_error(&env))?;
+		Ok(word.with_options(
+			options.max_distance.unwrap_or(0),
+			options.phonetic.unwrap_or(false),
+			options.reversed.unwrap_or(false),
+		))
+	}
+
+	/// Sets the matching options of the word, and of the rest of its phrase.
+	fn with_options(self, max_distance: u32, phonetic: bool, reversed: bool) -> Self {
+		let phrase = self.phrase.into_iter().map(|word| word.with_options(max_distance, phonetic, reversed)).collect();
+		Word { max_distance, phonetic, reversed, phrase, ..self }
+	}
+
+	/// The parts of the word, not including the any wildcards that unbind its
+	/// boundaries, see `boundLeft` and `boundRight`.
+	///
+	/// @remarks
+	///
+	/// Reading the parts of a phrase pattern throws an error, as a single list
+	/// of parts cannot hold the words of a phrase, and `Word.fromParts` cannot
+	/// build them back.
+	#[napi(getter, js_name = "parts")]
+	pub fn js_parts(&self) -> Result<Vec<WordPartObject>, Error> {
+		if !self.phrase.is_empty() {
+			return Err(Error::from_reason("The parts of a phrase cannot be read"));
+		}
+
+		Ok(self.parts.iter().map(WordPartObject::from).collect())
 	}
 
 	/// Matches a `Word` against a `Sentence`.
@@ -162,38 +450,209 @@ impl Word {
 	/// instance, it will still try to match the word against the entire
 	/// sentence, even if it has already been matched, returning `false`.
 	pub fn matches(&self, sentence: &mut Sentence) -> bool {
-		let mut matched = false;
+		!self.find(sentence).is_empty()
+	}
+
+	/// Matches a `Word` against a `Sentence` like [`matches`](Word::matches),
+	/// returning the position of every match within the sentence's contents.
+	pub fn find(&self, sentence: &mut Sentence) -> Vec<WordMatch> {
+		let matches = if self.case_sensitive || self.literal {
+			self.find_in_view(sentence)
+		} else {
+			self.find_normalized(sentence)
+		};
+
+		if !matches.is_empty() {
+			sentence.record_pattern(self);
+		}
+
+		matches
+	}
+
+	/// Matches the word against the contents of a `Sentence`, ignoring the
+	/// flags of the word.
+	fn find_normalized(&self, sentence: &mut Sentence) -> Vec<WordMatch> {
+		if !self.phrase.is_empty() {
+			let tokens = sentence.tokens();
+			return self.find_phrase(sentence, &tokens);
+		}
+
+		self.find_in_spans(sentence)
+	}
+
+	/// Matches the word against a view of a `Sentence` read with the flags of
+	/// the word, marking the characters of the sentence the matches come from.
+	fn find_in_view(&self, sentence: &mut Sentence) -> Vec<WordMatch> {
+		let mut view = sentence.view(self.case_sensitive, self.literal);
+
+		let mut matches = Vec::new();
+		for found in self.find_normalized(&mut view.sentence) {
+			let (start, end) = view.to_sentence_range(found.start, found.end);
+			sentence.mark_match(WordMatch { start, end, ..found }, &mut matches);
+		}
+
+		matches
+	}
+
+	/// Matches the word against every span of a `Sentence`, ignoring the rest
+	/// of its phrase.
+	fn find_in_spans(&self, sentence: &mut Sentence) -> Vec<WordMatch> {
+		let mut matches = Vec::new();
 		for (start, end) in sentence.spans.clone().iter() {
 			// If the word is longer than the sentence span, skip it:
-			if self.parts.len() > (end - start) {
+			if self.is_longer_than(end - start) {
 				continue;
 			}
 
-			let matches = match (self.bound_left, self.bound_right) {
-				(true, true) => self.matches_full(sentence, *start, *end),
-				(true, false) => self.matches_prefix(sentence, *start, *end),
-				(false, true) => self.matches_suffix(sentence, *start, *end),
-				(false, false) => self.matches_infix(sentence, *start, *end),
-			};
+			self.find_in_span(sentence, *start, *end, &mut matches, &mut ());
+		}
 
-			if matches {
-				matched = true;
+		matches
+	}
+
+	/// Matches the word against consecutive tokens among `tokens`, reading
+	/// the sentence with the flags of the word, see
+	/// [`find_phrase`](Word::find_phrase). A word without a phrase matches
+	/// within a single token.
+	pub(crate) fn find_in_tokens(&self, sentence: &mut Sentence, tokens: &[(usize, usize)]) -> Vec<WordMatch> {
+		if !self.case_sensitive && !self.literal {
+			return self.find_phrase(sentence, tokens);
+		}
+
+		let mut view = sentence.view(self.case_sensitive, self.literal);
+		let view_tokens: Vec<(usize, usize)> =
+			tokens.iter().map(|(start, end)| view.to_view_range(*start, *end)).collect();
+
+		let mut matches = Vec::new();
+		for found in self.find_phrase(&mut view.sentence, &view_tokens) {
+			let (start, end) = view.to_sentence_range(found.start, found.end);
+			sentence.mark_match(WordMatch { start, end, ..found }, &mut matches);
+		}
+
+		matches
+	}
+
+	/// Matches a phrase pattern, where every word of the phrase must match a
+	/// consecutive token of `tokens`, with its own boundaries within the
+	/// token. The tokens of a phrase must share the same span, so marked
+	/// tokens and protected ranges break the phrase, and every match covers
+	/// all its tokens.
+	fn find_phrase(&self, sentence: &mut Sentence, tokens: &[(usize, usize)]) -> Vec<WordMatch> {
+		let words: Vec<&Word> = std::iter::once(self).chain(&self.phrase).collect();
+		let base = sentence.snapshot();
+
+		let mut matches: Vec<WordMatch> = Vec::new();
+		let mut i = 0usize;
+		while i + words.len() <= tokens.len() {
+			let window = &tokens[i..i + words.len()];
+			let (start, end) = (window[0].0, window[window.len() - 1].1);
+			if !sentence.spans.iter().any(|(span_start, span_end)| *span_start <= start && end <= *span_end) {
+				i += 1;
+				continue;
+			}
+
+			// Match every word within its token, keeping the first match:
+			let mut found = Vec::with_capacity(words.len());
+			for (word, (start, end)) in words.iter().zip(window) {
+				let mut token_matches = Vec::new();
+				if !word.is_longer_than(end - start) {
+					word.find_in_span(sentence, *start, *end, &mut token_matches, &mut ());
+				}
+
+				match token_matches.first() {
+					Some(first) => found.push(*first),
+					None => break,
+				}
 			}
+
+			// The words marked the tokens they matched, undo their marks so only whole
+			// phrases are marked:
+			if !found.is_empty() {
+				sentence.apply_snapshot(&base);
+				for accepted in &matches {
+					sentence.mark(accepted.start, accepted.end);
+				}
+			}
+
+			if found.len() < words.len() {
+				i += 1;
+				continue;
+			}
+
+			let kind = found.iter().map(|found| found.kind).find(|kind| *kind != MatchKind::Literal);
+			let phrase = WordMatch {
+				start: found[0].start,
+				end: found[found.len() - 1].end,
+				distance: found.iter().map(|found| found.distance).sum(),
+				kind: kind.unwrap_or(MatchKind::Literal),
+				confusable: false,
+			};
+
+			sentence.mark_match(phrase, &mut matches);
+			i += words.len();
 		}
 
-		matched
+		matches
+	}
+
+	/// Whether or not the word cannot fit in `length` characters, even after
+	/// removing as many parts as the maximum distance allows. Phonetic words
+	/// always fit, as letters such as the `"c"` in `"fuck"` may be silent.
+	fn is_longer_than(&self, length: usize) -> bool {
+		!self.phonetic && self.parts.len() > length + self.max_distance as usize
+	}
+
+	/// The amount of characters the pattern spans, counting every part and the
+	/// separator between the words of a phrase once, plus the maximum distance.
+	pub(crate) fn pattern_length(&self) -> usize {
+		let phrase: usize = self.phrase.iter().map(|next| 1 + next.pattern_length()).sum();
+		self.parts.len() + self.max_distance as usize + phrase
+	}
+
+	/// Matches the word within a span of a `Sentence`, using the matcher for
+	/// the word's boundaries, and then the reversed, fuzzy, and phonetic
+	/// matchers if they were enabled for the word.
+	fn find_in_span<T: Tracer>(
+		&self,
+		sentence: &mut Sentence,
+		start: usize,
+		end: usize,
+		matches: &mut Vec<WordMatch>,
+		tracer: &mut T,
+	) {
+		match (self.bound_left, self.bound_right) {
+			(true, true) => self.matches_full(sentence, start, end, matches, tracer),
+			(true, false) => self.matches_prefix(sentence, start, end, matches, tracer),
+			(false, true) => self.matches_suffix(sentence, start, end, matches, tracer),
+			(false, false) => self.matches_infix(sentence, start, end, matches, tracer),
+		};
+
+		if self.reversed {
+			self.matches_reversed(sentence, start, end, matches, tracer);
+		}
+
+		if self.max_distance > 0 {
+			self.matches_fuzzy(sentence, start, end, matches, tracer);
+		}
+
+		if self.phonetic {
+			self.matches_phonetic(sentence, start, end, matches, tracer);
+		}
 	}
 
 	/// Matches a full word within a sentence, iterating over `Sentence`'s
 	/// `spans` finding the `Start` word boundaries and matching until the `End`
 	/// word boundary.
-	fn matches_full(&self, sentence: &mut Sentence, start: usize, end: usize) -> bool {
+	fn matches_full<T: Tracer>(
+		&self,
+		sentence: &mut Sentence,
+		start: usize,
+		end: usize,
+		matches: &mut Vec<WordMatch>,
+		tracer: &mut T,
+	) {
 		let last_part_index = self.parts.len() - 1;
 
-		let mut matched = false;
-		let mut word_index = 0usize;
-		let mut previous_character = None;
-
 		// Iterate over a sentence span:
 		for i in start..end {
 			// Find the start of the word:
@@ -201,12 +660,16 @@ impl Word {
 				continue;
 			}
 
-			if !self.parts[word_index].matches(sentence.contents[i], previous_character) {
+			if !self.parts[0].matches(sentence.contents[i], None) {
 				continue;
 			}
 
 			let match_start = i;
 			let mut match_end = usize::MAX;
+			let mut word_index = 0usize;
+			let mut previous_character = None;
+			let mut reason = AbandonReason::EndOfSpan;
+			tracer.candidate(i);
 
 			// Iterate over a possible word:
 			for i in i..end {
@@ -215,11 +678,13 @@ impl Word {
 
 				// If the boundary is not a word boundary, skip it:
 				if boundary == Boundary::NoContent {
+					tracer.step(i, character, TraceStepKind::Skipped, None);
 					continue;
 				}
 
 				// If the current word index matches the character, move to the next character:
 				if self.parts[word_index].matches(character, previous_character) {
+					tracer.step(i, character, TraceStepKind::Matched, Some((word_index, &self.parts[word_index])));
 					previous_character = Some(character);
 					if word_index != last_part_index {
 						word_index += 1;
@@ -230,33 +695,39 @@ impl Word {
 					continue;
 				}
 
-				// If it does, and neither does the last character, reset the state:
-				if !self.parts[word_index - 1].matches(character, previous_character) {
-					word_index = 0;
-					previous_character = None;
+				// If it does not, and neither does the previous part, abandon the candidate:
+				if word_index == 0 || !self.parts[word_index - 1].matches(character, previous_character) {
+					tracer.step(i, character, TraceStepKind::Mismatched, None);
+					reason = AbandonReason::Mismatch;
 					break;
 				}
+
+				tracer.step(i, character, TraceStepKind::Repeated, Some((word_index - 1, &self.parts[word_index - 1])));
 			}
 
 			if match_end != usize::MAX {
-				matched = true;
-				sentence.mark(match_start, match_end);
+				let found = WordMatch::literal(match_start, match_end + 1);
+				let marked = sentence.mark_match(found, matches);
+				tracer.complete(&found, marked);
+			} else {
+				tracer.abandon(reason);
 			}
 		}
-
-		matched
 	}
 
 	/// Matches a prefix within a sentence, iterating over `Sentence`'s `spans`
 	/// finding the `Start` word boundaries and matching until the `End` word
 	/// boundary.
-	fn matches_prefix(&self, sentence: &mut Sentence, start: usize, end: usize) -> bool {
+	fn matches_prefix<T: Tracer>(
+		&self,
+		sentence: &mut Sentence,
+		start: usize,
+		end: usize,
+		matches: &mut Vec<WordMatch>,
+		tracer: &mut T,
+	) {
 		let last_part_index = self.parts.len() - 1;
 
-		let mut matched = false;
-		let mut word_index = 0usize;
-		let mut previous_character = None;
-
 		// Iterate over a sentence span:
 		for i in start..end {
 			// Find the start of the word:
@@ -264,12 +735,16 @@ impl Word {
 				continue;
 			}
 
-			if !self.parts[word_index].matches(sentence.contents[i], previous_character) {
+			if !self.parts[0].matches(sentence.contents[i], None) {
 				continue;
 			}
 
 			let match_start = i;
 			let mut match_end = usize::MAX;
+			let mut word_index = 0usize;
+			let mut previous_character = None;
+			let mut reason = AbandonReason::EndOfSpan;
+			tracer.candidate(i);
 
 			// Iterate over a possible word:
 			for i in i..end {
@@ -278,11 +753,13 @@ impl Word {
 
 				// If the boundary is not a word boundary, skip it:
 				if boundary == Boundary::NoContent {
+					tracer.step(i, character, TraceStepKind::Skipped, None);
 					continue;
 				}
 
 				// If the current word index matches the character, move to the next character:
 				if self.parts[word_index].matches(character, previous_character) {
+					tracer.step(i, character, TraceStepKind::Matched, Some((word_index, &self.parts[word_index])));
 					previous_character = Some(character);
 					if word_index != last_part_index {
 						word_index += 1;
@@ -293,59 +770,71 @@ impl Word {
 					continue;
 				}
 
-				// If it does, and neither does the last character, reset the state:
-				if !self.parts[word_index - 1].matches(character, previous_character) {
-					word_index = 0;
-					previous_character = None;
+				// If it does not, and neither does the previous part, abandon the candidate:
+				if word_index == 0 || !self.parts[word_index - 1].matches(character, previous_character) {
+					tracer.step(i, character, TraceStepKind::Mismatched, None);
+					reason = AbandonReason::Mismatch;
 					break;
 				}
+
+				tracer.step(i, character, TraceStepKind::Repeated, Some((word_index - 1, &self.parts[word_index - 1])));
 			}
 
 			if match_end != usize::MAX {
-				matched = true;
-				sentence.mark(match_start, match_end);
+				let found = WordMatch::literal(match_start, match_end + 1);
+				let marked = sentence.mark_match(found, matches);
+				tracer.complete(&found, marked);
+			} else {
+				tracer.abandon(reason);
 			}
 		}
-
-		matched
 	}
 
 	/// Matches a suffix within a sentence, iterating over `Sentence`'s `spans`
 	/// finding the `End` word boundaries and matching in reverse until the
 	/// `End` word boundary.
-	fn matches_suffix(&self, sentence: &mut Sentence, start: usize, end: usize) -> bool {
+	fn matches_suffix<T: Tracer>(
+		&self,
+		sentence: &mut Sentence,
+		start: usize,
+		end: usize,
+		matches: &mut Vec<WordMatch>,
+		tracer: &mut T,
+	) {
 		let last_part_index = self.parts.len() - 1;
 
-		let mut matched = false;
-		let mut word_index = last_part_index;
-		let mut previous_character = None;
-
 		// Iterate over a sentence span:
 		for i in (start..end).rev() {
-			// Find the start of the word:
+			// Find the end of the word:
 			if !sentence.boundaries[i].is_end() {
 				continue;
 			}
 
-			if !self.parts[word_index].matches(sentence.contents[i], previous_character) {
+			if !self.parts[last_part_index].matches(sentence.contents[i], None) {
 				continue;
 			}
 
 			let match_start = i;
 			let mut match_end = usize::MAX;
+			let mut word_index = last_part_index;
+			let mut previous_character = None;
+			let mut reason = AbandonReason::EndOfSpan;
+			tracer.candidate(i);
 
 			// Iterate over a possible word:
-			for i in (start..end).rev() {
+			for i in (start..=i).rev() {
 				let character = sentence.contents[i];
 				let boundary = sentence.boundaries[i];
 
 				// If the boundary is not a word boundary, skip it:
 				if boundary == Boundary::NoContent {
+					tracer.step(i, character, TraceStepKind::Skipped, None);
 					continue;
 				}
 
 				// If the current word index matches the character, move to the next character:
 				if self.parts[word_index].matches(character, previous_character) {
+					tracer.step(i, character, TraceStepKind::Matched, Some((word_index, &self.parts[word_index])));
 					previous_character = Some(character);
 					if word_index != 0 {
 						word_index -= 1;
@@ -356,41 +845,51 @@ impl Word {
 					continue;
 				}
 
-				// If it does, and neither does the last character, reset the state:
-				if !self.parts[word_index + 1].matches(character, previous_character) {
-					word_index = last_part_index;
-					previous_character = None;
+				// If it does not, and neither does the previous part, abandon the candidate:
+				if word_index == last_part_index || !self.parts[word_index + 1].matches(character, previous_character) {
+					tracer.step(i, character, TraceStepKind::Mismatched, None);
+					reason = AbandonReason::Mismatch;
 					break;
 				}
+
+				tracer.step(i, character, TraceStepKind::Repeated, Some((word_index + 1, &self.parts[word_index + 1])));
 			}
 
 			if match_end != usize::MAX {
-				matched = true;
-				sentence.mark(match_start, match_end);
+				let found = WordMatch::literal(match_end, match_start + 1);
+				let marked = sentence.mark_match(found, matches);
+				tracer.complete(&found, marked);
+			} else {
+				tracer.abandon(reason);
 			}
 		}
-
-		matched
 	}
 
 	/// Matches an infix within a sentence, iterating over `Sentence`'s `spans`
 	/// finding the `Start` word boundaries and matching until the `End` word
 	/// boundary.
-	fn matches_infix(&self, sentence: &mut Sentence, start: usize, end: usize) -> bool {
+	fn matches_infix<T: Tracer>(
+		&self,
+		sentence: &mut Sentence,
+		start: usize,
+		end: usize,
+		matches: &mut Vec<WordMatch>,
+		tracer: &mut T,
+	) {
 		let last_part_index = self.parts.len() - 1;
 
-		let mut matched = false;
-		let mut word_index = 0usize;
-		let mut previous_character = None;
-
 		// Iterate over a sentence span:
 		for i in start..end {
-			if !self.parts[word_index].matches(sentence.contents[i], previous_character) {
+			if !self.parts[0].matches(sentence.contents[i], None) {
 				continue;
 			}
 
 			let match_start = i;
 			let mut match_end = usize::MAX;
+			let mut word_index = 0usize;
+			let mut previous_character = None;
+			let mut reason = AbandonReason::EndOfSpan;
+			tracer.candidate(i);
 
 			// Iterate over a possible word:
 			for i in i..end {
@@ -399,11 +898,13 @@ impl Word {
 
 				// If the boundary is not a word boundary, skip it:
 				if boundary == Boundary::NoContent {
+					tracer.step(i, character, TraceStepKind::Skipped, None);
 					continue;
 				}
 
 				// If the current word index matches the character, move to the next character:
 				if self.parts[word_index].matches(character, previous_character) {
+					tracer.step(i, character, TraceStepKind::Matched, Some((word_index, &self.parts[word_index])));
 					previous_character = Some(character);
 					if word_index != last_part_index {
 						word_index += 1;
@@ -414,21 +915,146 @@ impl Word {
 					continue;
 				}
 
-				// If it does, and neither does the last character, reset the state:
-				if !self.parts[word_index - 1].matches(character, previous_character) {
-					word_index = 0;
-					previous_character = None;
+				// If it does not, and neither does the previous part, abandon the candidate:
+				if word_index == 0 || !self.parts[word_index - 1].matches(character, previous_character) {
+					tracer.step(i, character, TraceStepKind::Mismatched, None);
+					reason = AbandonReason::Mismatch;
 					break;
 				}
+
+				tracer.step(i, character, TraceStepKind::Repeated, Some((word_index - 1, &self.parts[word_index - 1])));
 			}
 
 			if match_end != usize::MAX {
-				matched = true;
-				sentence.mark(match_start, match_end);
+				let found = WordMatch::literal(match_start, match_end + 1);
+				let marked = sentence.mark_match(found, matches);
+				tracer.complete(&found, marked);
+			} else {
+				tracer.abandon(reason);
 			}
 		}
+	}
+
+	/// Matches the word within the words of a span that were not marked, read
+	/// backwards, allowing up to `max_distance` edits.
+	///
+	/// The position of the match is mapped back to the characters of the
+	/// sentence, so it is marked like a match that was read forwards.
+	fn matches_reversed<T: Tracer>(
+		&self,
+		sentence: &mut Sentence,
+		start: usize,
+		end: usize,
+		matches: &mut Vec<WordMatch>,
+		tracer: &mut T,
+	) {
+		let max_distance = self.max_distance as usize;
+		self.matches_words(sentence, start, end, matches, tracer, |token| {
+			let reversed: Vec<char> = token.iter().rev().copied().collect();
+			find_fuzzy(&self.parts, &reversed, self.bound_left, self.bound_right, max_distance).map(|found| WordMatch {
+				start: token.len() - found.end,
+				end: token.len() - found.start,
+				distance: found.distance,
+				kind: MatchKind::Reversed,
+				confusable: false,
+			})
+		});
+	}
+
+	/// Matches the word within the words of a span that were not marked,
+	/// allowing up to `max_distance` edits, see [`find_fuzzy`].
+	fn matches_fuzzy<T: Tracer>(
+		&self,
+		sentence: &mut Sentence,
+		start: usize,
+		end: usize,
+		matches: &mut Vec<WordMatch>,
+		tracer: &mut T,
+	) {
+		let max_distance = self.max_distance as usize;
+		self.matches_words(sentence, start, end, matches, tracer, |token| {
+			find_fuzzy(&self.parts, token, self.bound_left, self.bound_right, max_distance).map(|found| WordMatch {
+				start: found.start,
+				end: found.end,
+				distance: found.distance,
+				kind: MatchKind::Fuzzy,
+				confusable: false,
+			})
+		});
+	}
+
+	/// Matches the word within the words of a span that were not marked,
+	/// comparing their phonetic keys, see [`phonetic_key`].
+	///
+	/// A word that is not bound to the left or to the right matches the words
+	/// whose key ends or starts with the word's key, respectively, and the
+	/// whole word of the sentence is marked.
+	fn matches_phonetic<T: Tracer>(
+		&self,
+		sentence: &mut Sentence,
+		start: usize,
+		end: usize,
+		matches: &mut Vec<WordMatch>,
+		tracer: &mut T,
+	) {
+		let key = phonetic_key_of_parts(&self.parts);
+		if key.is_empty() {
+			return;
+		}
+
+		self.matches_words(sentence, start, end, matches, tracer, |token| {
+			let token_key = phonetic_key(token.iter().copied());
+			let matched = match (self.bound_left, self.bound_right) {
+				(true, true) => token_key == key,
+				(true, false) => token_key.starts_with(&key),
+				(false, true) => token_key.ends_with(&key),
+				(false, false) => token_key.contains(&key),
+			};
 
-		matched
+			matched.then_some(WordMatch { kind: MatchKind::Phonetic, ..WordMatch::literal(0, token.len()) })
+		});
+	}
+
+	/// Runs `find` over every word of a span that was not marked, marking the
+	/// match it returns, if any. The position of the match is relative to the
+	/// start of the word.
+	///
+	/// Unlike the other matchers, a word of the sentence is compared as a
+	/// whole, so non-word characters within it are not skipped.
+	fn matches_words<T: Tracer, F: Fn(&[char]) -> Option<WordMatch>>(
+		&self,
+		sentence: &mut Sentence,
+		start: usize,
+		end: usize,
+		matches: &mut Vec<WordMatch>,
+		tracer: &mut T,
+		find: F,
+	) {
+		let is_word = |boundary: &Boundary| !matches!(boundary, Boundary::NoContent | Boundary::Marked);
+
+		let mut i = start;
+		while i < end {
+			// Find the start of the next word that was not marked:
+			if !is_word(&sentence.boundaries[i]) {
+				i += 1;
+				continue;
+			}
+
+			let word_start = i;
+			while i < end && is_word(&sentence.boundaries[i]) {
+				i += 1;
+			}
+
+			tracer.candidate(word_start);
+			match find(&sentence.contents[word_start..i]) {
+				Some(found) => {
+					let found = WordMatch { start: word_start + found.start, end: word_start + found.end, ..found };
+					let marked = sentence.mark_match(found, matches);
+					tracer.complete(&found, marked);
+				}
+				None => tracer.abandon(AbandonReason::Mismatch),
+			}
+		}
 	}
 
 	#[napi(js_name = "matches")]
@@ -436,6 +1062,58 @@ impl Word {
 		self.matches(sentence)
 	}
 
+	/// Matches a `Word` against a `Sentence` like `matches`, returning the
+	/// position of every match within the sentence's contents.
+	#[napi(js_name = "find")]
+	pub fn js_find(&self, sentence: &mut Sentence) -> Vec<WordMatch> {
+		self.find(sentence)
+	}
+
+	/// Matches the word against a text, returning a trace of every span,
+	/// candidate, and character that was read, along with the reason why each
+	/// candidate was abandoned.
+	///
+	/// @param input - The text to match the word against.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const trace = new Word("**ass**").explain("Hello, class!");
+	/// // ⇒ { normalized: "hello, class!", pattern: "**ass**", matched: true, spans: [...] }
+	/// ```
+	///
+	/// @remarks
+	///
+	/// This method is intended for debugging patterns, as recording the trace
+	/// is slower than matching. Phrase patterns only trace their first word.
+	#[napi]
+	pub fn explain(&self, input: String) -> Result<MatchTrace, Error> {
+		let mut sentence = Sentence::new(input);
+		if self.case_sensitive || self.literal {
+			sentence = sentence.view(self.case_sensitive, self.literal).sentence;
+		}
+		let mut matches = Vec::new();
+		let mut spans = Vec::new();
+
+		for (start, end) in sentence.spans.clone() {
+			let mut span = TraceSpan { start: start as u32, end: end as u32, skipped: false, candidates: Vec::new() };
+			if self.is_longer_than(end - start) {
+				span.skipped = true;
+			} else {
+				self.find_in_span(&mut sentence, start, end, &mut matches, &mut span);
+			}
+
+			spans.push(span);
+		}
+
+		Ok(MatchTrace {
+			normalized: sentence.to_string(),
+			pattern: self.pattern(),
+			matched: !matches.is_empty(),
+			spans,
+		})
+	}
+
 	#[napi(getter, js_name = "length")]
 	pub fn js_length(&self) -> u32 {
 		self.parts.len().try_into().unwrap()
@@ -443,8 +1121,25 @@ impl Word {
 
 	#[napi]
 	pub fn to_string(&self) -> Result<String, Error> {
+		Ok(self.pattern())
+	}
+
+	/// Writes the pattern of the word back, including its flags and the rest
+	/// of its phrase.
+	pub(crate) fn pattern(&self) -> String {
 		let mut word = String::new();
 
+		if self.case_sensitive || self.literal {
+			word.push_str(FLAGS_START);
+			if self.case_sensitive {
+				word.push(FLAG_CASE_SENSITIVE);
+			}
+			if self.literal {
+				word.push(FLAG_LITERAL);
+			}
+			word.push(FLAGS_END);
+		}
+
 		if !self.bound_left {
 			word.push_str("**");
 		}
@@ -457,6 +1152,11 @@ impl Word {
 			word.push_str("**");
 		}
 
-		Ok(word)
+		for next in &self.phrase {
+			word.push(' ');
+			word.push_str(&next.pattern());
+		}
+
+		word
 	}
 }
@@ -0,0 +1,147 @@
+use std::sync::{
+	atomic::{AtomicBool, Ordering},
+	Arc,
+};
+
+use napi::{bindgen_prelude::AsyncTask, Env, Error, JsFunction, JsObject};
+
+use crate::{
+	batch::{match_parallel, BatchMatchResult, MatchBatchTask},
+	error::ParseError,
+	sentence::Sentence,
+	word::Word,
+};
+
+/// Parses a list of patterns, throwing the JavaScript error of the first
+/// pattern that cannot be parsed, with a `word` property holding its index
+/// besides `code` and `index`.
+pub(crate) fn parse_words(env: &Env, patterns: &[String]) -> Result<Vec<Word>, Error> {
+	let mut words = Vec::with_capacity(patterns.len());
+	for (index, pattern) in patterns.iter().enumerate() {
+		match Word::parse(pattern) {
+			Ok(word) => words.push(word),
+			Err(error) => {
+				let mut error = error.to_js_error(env)?;
+				error.set_named_property("word", index as u32)?;
+				return Err(Error::from(error.into_unknown()));
+			}
+		}
+	}
+
+	Ok(words)
+}
+
+/// A compiled list of `Word`s that are matched together against a
+/// `Sentence`.
+///
+/// The words are shared between the list and the tasks it spawns, so
+/// matching a batch in the background does not need to compile them again.
+#[napi]
+#[derive(Clone)]
+pub struct WordList {
+	pub(crate) words: Arc<Vec<Word>>,
+}
+
+#[napi]
+impl WordList {
+	/// @remarks
+	///
+	/// If a pattern cannot be parsed, the thrown error has a `word` property
+	/// with the index of the offending pattern, besides `code` and `index`.
+	#[napi(constructor)]
+	pub fn new(env: Env, words: Vec<String>) -> Result<Self, Error> {
+		Ok(WordList { words: Arc::new(parse_words(&env, &words)?) })
+	}
+
+	/// Parses a list of patterns into a `WordList`.
+	pub fn parse<S: AsRef<str>>(words: &[S]) -> Result<Self, ParseError> {
+		let words = words.iter().map(|word| Word::parse(word.as_ref())).collect::<Result<Vec<_>, _>>()?;
+		Ok(WordList { words: Arc::new(words) })
+	}
+
+	/// Matches every `Word` of the list against a `Sentence`, returning the
+	/// indexes of the words that matched.
+	///
+	/// Every word is run against the sentence, even if a previous word already
+	/// matched, so all the matches are marked.
+	pub fn matches(&self, sentence: &mut Sentence) -> Vec<u32> {
+		let mut matched = Vec::new();
+		for (index, word) in self.words.iter().enumerate() {
+			if word.matches(sentence) {
+				matched.push(index as u32);
+			}
+		}
+
+		matched
+	}
+
+	#[napi(js_name = "matches")]
+	pub fn js_matches(&self, sentence: &mut Sentence) -> bool {
+		!self.matches(sentence).is_empty()
+	}
+
+	/// Matches the list against a batch of messages in the libuv thread pool,
+	/// resolving with one result per message, in the same order.
+	///
+	/// @param messages - The messages to match against.
+	/// @param signal - An optional `AbortSignal` to cancel the task.
+	///
+	/// @example
+	///
+	/// ```ts
+	/// const list = new WordList(["pepe", "banana"]);
+	/// const results = await list.matchBatch(["Pepe ate a banana", "Hello world"]);
+	/// // ⇒ [{ matched: true, words: [0, 1], edits: [...] }, { matched: false, words: [], edits: [] }]
+	/// ```
+	///
+	/// @remarks
+	///
+	/// Aborting the signal rejects the promise with an `AbortError` once the
+	/// message being matched is done, and the rest of the messages are skipped.
+	/// A signal that is already aborted rejects without matching any message.
+	#[napi(js_name = "matchBatch", ts_return_type = "Promise<Array<BatchMatchResult>>")]
+	pub fn js_match_batch(
+		&self,
+		env: Env,
+		messages: Vec<String>,
+		#[napi(ts_arg_type = "AbortSignal | undefined | null")] signal: Option<JsObject>,
+	) -> Result<AsyncTask<MatchBatchTask>, Error> {
+		let aborted = Arc::new(AtomicBool::new(false));
+		if let Some(signal) = signal {
+			// Abort events are not fired for a signal that was aborted before the call:
+			aborted.store(signal.get_named_property::<bool>("aborted")?, Ordering::Relaxed);
+
+			let flag = Arc::clone(&aborted);
+			let listener = env.create_function_from_closure("onabort", move |_| {
+				flag.store(true, Ordering::Relaxed);
+				Ok(())
+			})?;
+			let add_event_listener: JsFunction = signal.get_named_property("addEventListener")?;
+			add_event_listener
+				.call(Some(&signal), &[env.create_string("abort")?.into_unknown(), listener.into_unknown()])?;
+		}
+
+		Ok(AsyncTask::new(MatchBatchTask { list: self.clone(), messages, aborted }))
+	}
+
+	/// Matches the list against a batch of messages in parallel, using as
+	/// many threads as CPU cores, returning one result per message, in the same
+	/// order.
+	///
+	/// @param messages - The messages to match against.
+	///
+	/// @remarks
+	///
+	/// This method blocks the calling thread until every message has been
+	/// matched, it is intended for large offline batches, such as scanning
+	/// archived messages. Use `matchBatch` to avoid blocking the event loop.
+	#[napi(js_name = "matchParallel")]
+	pub fn js_match_parallel(&self, messages: Vec<String>) -> Vec<BatchMatchResult> {
+		match_parallel(self, &messages)
+	}
+
+	#[napi(getter, js_name = "length")]
+	pub fn js_length(&self) -> u32 {
+		self.words.len().try_into().unwrap()
+	}
+}
@@ -1,6 +1,51 @@
 use std::fmt::{Display, Formatter, Result};
 
-use crate::constants::*;
+use crate::{
+	confusables::Confusable,
+	constants::*,
+	sentence::{compose_single_mark, is_foldable_mark},
+};
+
+/// The kind of a `WordPart`, see `WordPartObject`.
+#[napi(string_enum = "camelCase")]
+#[derive(PartialEq)]
+pub enum WordPartKind {
+	/// A single character, such as `"a"`.
+	Single,
+	/// A group of characters, such as `"[abc]"`.
+	Group,
+	/// A single wildcard, `"*"`.
+	SingleWildcard,
+	/// An any wildcard, `"**"`.
+	AnyWildcard,
+}
+
+/// A part of a `Word` as a plain object, used to read the parts of a word and
+/// to build a word without writing its pattern.
+///
+/// @example
+///
+/// ```ts
+/// const word = Word.fromParts([
+/// 	{ kind: "single", char: "b" },
+/// 	{ kind: "group", chars: ["a", "4"] },
+/// 	{ kind: "single", char: "r" },
+/// ]);
+/// word.toString();
+/// // ⇒ "b[a4]r"
+/// ```
+#[derive(Clone)]
+#[napi(object)]
+pub struct WordPartObject {
+	/// The kind of the part.
+	pub kind: WordPartKind,
+	/// The character of a `single` part.
+	#[napi(js_name = "char")]
+	pub character: Option<String>,
+	/// The characters of a `group` part.
+	#[napi(js_name = "chars")]
+	pub characters: Option<Vec<String>>,
+}
 
 #[derive(PartialEq, Clone)]
 pub enum WordPart {
@@ -43,12 +88,20 @@ impl Display for WordPart {
 	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
 		match self {
 			WordPart::Single(character) => match *character {
-				ASTERISK => write!(f, "{ESCAPE}{ASTERISK}"),
-				GROUP_START => write!(f, "{ESCAPE}{ASTERISK}"),
+				ASTERISK | GROUP_START | ESCAPE => write!(f, "{ESCAPE}{character}"),
+				// Whitespace outside groups separates the words of a phrase:
+				c if c.is_whitespace() => write!(f, "{GROUP_START}{c}{GROUP_END}"),
 				_ => write!(f, "{character}"),
 			},
 			WordPart::Group(characters) => {
-				write!(f, "{GROUP_START}{0}{GROUP_END}", characters.iter().collect::<String>())
+				write!(f, "{GROUP_START}")?;
+				for character in characters {
+					match *character {
+						GROUP_END | ESCAPE => write!(f, "{ESCAPE}{character}")?,
+						_ => write!(f, "{character}")?,
+					}
+				}
+				write!(f, "{GROUP_END}")
 			}
 			WordPart::SingleWildcard => write!(f, "{ASTERISK}"),
 			WordPart::AnyWildcard => write!(f, "{ASTERISK}{ASTERISK}"),
@@ -69,3 +122,87 @@ impl WordPart {
 		}
 	}
 }
+
+impl From<&WordPart> for WordPartObject {
+	fn from(part: &WordPart) -> Self {
+		match part {
+			WordPart::Single(character) => {
+				WordPartObject { kind: WordPartKind::Single, character: Some(character.to_string()), characters: None }
+			}
+			WordPart::Group(characters) => WordPartObject {
+				kind: WordPartKind::Group,
+				character: None,
+				characters: Some(characters.iter().map(char::to_string).collect()),
+			},
+			WordPart::SingleWildcard => {
+				WordPartObject { kind: WordPartKind::SingleWildcard, character: None, characters: None }
+			}
+			WordPart::AnyWildcard => {
+				WordPartObject { kind: WordPartKind::AnyWildcard, character: None, characters: None }
+			}
+		}
+	}
+}
+
+impl WordPart {
+	/// Reads the parts described by a `WordPartObject`, sanitizing their
+	/// characters like the patterns read by `Word::parse`.
+	///
+	/// A `single` part whose character is sanitized into several characters
+	/// (such as `"⑩"`) is read as several parts, and a `group` part with a
+	/// single character is read as a `single` part. Empty groups are skipped.
+	///
+	/// Characters may be followed by combining marks, which are composed or
+	/// folded into them, see [`read_character`].
+	pub(crate) fn from_object(object: WordPartObject) -> std::result::Result<Vec<WordPart>, &'static str> {
+		match object.kind {
+			WordPartKind::Single => {
+				let Some(character) = read_character(&object.character.unwrap_or_default()) else {
+					return Err("A single part must have exactly one character");
+				};
+
+				if character.is_whitespace() {
+					return Err("A single part cannot be whitespace");
+				}
+
+				Ok(character.replace_confusables().to_lowercase().chars().map(WordPart::Single).collect())
+			}
+			WordPartKind::Group => {
+				let mut group: Vec<char> = Vec::new();
+				for character in object.characters.unwrap_or_default() {
+					let Some(character) = read_character(&character) else {
+						return Err("The characters of a group part must be single characters");
+					};
+
+					for c in character.replace_confusables().to_lowercase().chars() {
+						if !group.contains(&c) {
+							group.push(c);
+						}
+					}
+				}
+
+				Ok(match group.len() {
+					0 => vec![],
+					1 => vec![WordPart::Single(group[0])],
+					_ => vec![WordPart::Group(group)],
+				})
+			}
+			WordPartKind::SingleWildcard => Ok(vec![WordPart::SingleWildcard]),
+			WordPartKind::AnyWildcard => Ok(vec![WordPart::AnyWildcard]),
+		}
+	}
+}
+
+/// Reads the character of a part, composing a single combining mark after it
+/// and folding stacks of marks, like `Word::parse` does, so `"e\u{301}"` is
+/// read as `"é"`. Returns `None` unless `character` is a single character
+/// followed by foldable marks.
+fn read_character(character: &str) -> Option<char> {
+	let characters: Vec<char> = character.chars().collect();
+	match characters.split_first() {
+		Some((c, marks)) if !is_foldable_mark(*c) && marks.iter().all(|mark| is_foldable_mark(*mark)) => {
+			Some(compose_single_mark(*c, marks).unwrap_or(*c))
+		}
+		_ => None,
+	}
+}
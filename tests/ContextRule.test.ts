@@ -0,0 +1,63 @@
+import { ContextRule, ParseErrorCode, Protection, Sentence, Word } from '../index';
+
+describe('ContextRule', () => {
+	test('GIVEN a required following word THEN only matches when it follows within the tokens', () => {
+		const rule = new ContextRule(new Word('kill'), { followedBy: { words: ['yourself', 'urself'], within: 2 } });
+
+		expect(rule.matches(new Sentence('go kill yourself'))).toBe(true);
+		expect(rule.matches(new Sentence('kill, uh, yourself'))).toBe(true);
+		expect(rule.matches(new Sentence('kill the process'))).toBe(false);
+		expect(rule.matches(new Sentence('kill the process yourself'))).toBe(false);
+	});
+
+	test('GIVEN a forbidden preceding word THEN does not match after it', () => {
+		const rule = new ContextRule(new Word('**ass'), { notPrecededBy: { words: ['kick', 'bad'] } });
+
+		expect(rule.matches(new Sentence('kick ass'))).toBe(false);
+		expect(rule.matches(new Sentence('bad dumbass'))).toBe(false);
+		expect(rule.matches(new Sentence('you ass'))).toBe(true);
+	});
+
+	test('GIVEN rejected matches THEN only marks the accepted ones', () => {
+		const rule = new ContextRule(new Word('kill'), { precededBy: { words: ['go'] } });
+		const sentence = new Sentence('kill it, go kill');
+
+		expect(rule.find(sentence).map((found) => [found.start, found.end])).toEqual([[12, 16]]);
+		expect(sentence.toEdits().map((edit) => [edit.start, edit.end])).toEqual([[12, 16]]);
+	});
+
+	test('GIVEN protected ranges THEN does not read them as tokens', () => {
+		const sentence = new Sentence('<@123> kill', { protect: [Protection.Mention] });
+
+		expect(new ContextRule(new Word('kill'), { precededBy: { words: ['123'] } }).matches(sentence)).toBe(false);
+	});
+
+	test('GIVEN a phrase condition THEN matches it over consecutive tokens', () => {
+		const rule = new ContextRule(new Word('kill'), { followedBy: { words: ['your self'], within: 2 } });
+
+		expect(rule.matches(new Sentence('kill your self'))).toBe(true);
+		expect(rule.matches(new Sentence('kill the your self'))).toBe(false);
+	});
+
+	test('GIVEN a condition with flags THEN reads the original characters', () => {
+		const rule = new ContextRule(new Word('kill'), { followedBy: { words: ['(?c)NASA'], within: 2 } });
+
+		expect(rule.matches(new Sentence('kill NASA'))).toBe(true);
+		expect(rule.matches(new Sentence('kill nasa'))).toBe(false);
+	});
+
+	test('GIVEN a condition word already marked THEN still fulfills the condition', () => {
+		const rule = new ContextRule(new Word('kill'), { followedBy: { words: ['yourself'] } });
+		const sentence = new Sentence('kill yourself');
+
+		expect(new Word('yourself').matches(sentence)).toBe(true);
+		expect(rule.matches(sentence)).toBe(true);
+	});
+
+	test('GIVEN an invalid pattern THEN throws', () => {
+		expect(() => new ContextRule(new Word('kill'), { followedBy: { words: ['[ab'] } })).toThrowError('Unterminated character group');
+		expect(() => new ContextRule(new Word('kill'), { followedBy: { words: ['you', '[ab'] } })).toThrowError(
+			expect.objectContaining({ code: ParseErrorCode.UnterminatedGroup, index: 0, word: 1 })
+		);
+	});
+});
@@ -0,0 +1,62 @@
+import { ConversationBuffer, WordList } from '../index';
+
+describe('ConversationBuffer', () => {
+	test('GIVEN a word split across messages THEN matches it with message offsets', () => {
+		const buffer = new ConversationBuffer(new WordList(['bar']), { size: 3 });
+
+		expect(buffer.push('channel', '1', 'b')).toEqual([]);
+		expect(buffer.push('channel', '2', 'a')).toEqual([]);
+		expect(buffer.push('channel', '3', 'r!')).toEqual([
+			{
+				word: 0,
+				messages: [
+					{ id: '1', start: 0, end: 1 },
+					{ id: '2', start: 0, end: 1 },
+					{ id: '3', start: 0, end: 1 }
+				]
+			}
+		]);
+	});
+
+	test('GIVEN a match already returned THEN does not return it again', () => {
+		const buffer = new ConversationBuffer(new WordList(['pepe']));
+
+		expect(buffer.push('channel', '1', '𝕡e pe and pe')).toEqual([{ word: 0, messages: [{ id: '1', start: 0, end: 6 }] }]);
+		expect(buffer.push('channel', '2', 'pe')).toEqual([
+			{
+				word: 0,
+				messages: [
+					{ id: '1', start: 11, end: 13 },
+					{ id: '2', start: 0, end: 2 }
+				]
+			}
+		]);
+	});
+
+	test('GIVEN different conversations or dropped messages THEN does not match across them', () => {
+		const buffer = new ConversationBuffer(new WordList(['bar']), { size: 2 });
+
+		buffer.push('channel', '1', 'b');
+		expect(buffer.push('other', '2', 'ar')).toEqual([]);
+
+		buffer.push('channel', '3', 'a');
+		buffer.push('channel', '4', 'hi');
+		expect(buffer.push('channel', '5', 'r')).toEqual([]);
+
+		buffer.push('channel', '6', 'ba');
+		buffer.clear('channel');
+		expect(buffer.push('channel', '7', 'r')).toEqual([]);
+	});
+
+	test('GIVEN more conversations than the maximum THEN drops the least recently used one', () => {
+		const buffer = new ConversationBuffer(new WordList(['bar']), { maxConversations: 2 });
+
+		buffer.push('first', '1', 'b');
+		buffer.push('second', '2', 'b');
+		buffer.push('first', '3', 'a');
+		buffer.push('third', '4', 'hi');
+
+		expect(buffer.push('first', '5', 'r')).toHaveLength(1);
+		expect(buffer.push('second', '6', 'ar')).toEqual([]);
+	});
+});
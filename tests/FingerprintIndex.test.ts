@@ -0,0 +1,39 @@
+import { FingerprintIndex, Sentence, fingerprintSimilarity } from '../index';
+
+describe('FingerprintIndex', () => {
+	const original = new Sentence('join my server at example').fingerprint();
+	const variant = new Sentence('j0in my s e r v e r at example!!').fingerprint();
+	const unrelated = new Sentence('what did you have for lunch today').fingerprint();
+
+	test('GIVEN confusables and spacing changes THEN fingerprints are similar', () => {
+		expect(new Sentence('JOIN MY SЕRVER AT EXAMPLE').fingerprint()).toBe(original);
+		expect(fingerprintSimilarity(original, variant)).toBeGreaterThanOrEqual(0.85);
+		expect(fingerprintSimilarity(original, unrelated)).toBeLessThan(0.85);
+	});
+
+	test('GIVEN an invalid fingerprint THEN throws', () => {
+		expect(() => fingerprintSimilarity('xyz', original)).toThrowError('A fingerprint must have 16 hexadecimal digits');
+	});
+
+	test('GIVEN a similar fingerprint THEN finds it', () => {
+		const index = new FingerprintIndex();
+		index.insert('1', original);
+		index.insert('2', unrelated);
+
+		expect(index.find(variant).map((match) => match.id)).toEqual(['1']);
+		expect(index.find(variant, 0).map((match) => match.id)).toEqual(['1', '2']);
+	});
+
+	test('GIVEN a full index THEN removes the oldest fingerprints', () => {
+		const index = new FingerprintIndex({ capacity: 2 });
+		index.insert('1', original);
+		index.insert('2', unrelated);
+		index.insert('3', unrelated);
+
+		expect(index.length).toBe(2);
+		expect(index.find(variant)).toEqual([]);
+
+		index.clear();
+		expect(index.length).toBe(0);
+	});
+});
@@ -0,0 +1,62 @@
+import { ParseErrorCode, ProximityRule, Sentence } from '../index';
+
+describe('ProximityRule', () => {
+	test('GIVEN words within the distance in any order THEN returns their combined span', () => {
+		const rule = new ProximityRule(['kill', 'you'], 3);
+		const found = rule.find(new Sentence('i will find you and kill him'));
+
+		expect(found.map((match) => [match.start, match.end])).toEqual([[12, 24]]);
+		expect(found[0].matches.map((match) => [match.start, match.end])).toEqual([
+			[20, 24],
+			[12, 15]
+		]);
+	});
+
+	test('GIVEN words farther than the distance THEN does not match', () => {
+		expect(new ProximityRule(['kill', 'you'], 2).matches(new Sentence('kill a b you'))).toBe(false);
+		expect(new ProximityRule(['kill', 'you'], 3).matches(new Sentence('kill a b you'))).toBe(true);
+		expect(new ProximityRule(['kill', 'you', 'tonight'], 5).matches(new Sentence('kill you'))).toBe(false);
+	});
+
+	test('GIVEN a match THEN only marks the matched words', () => {
+		const sentence = new Sentence('tonight i kill you');
+
+		expect(new ProximityRule(['kill', 'tonight'], 5).matches(sentence)).toBe(true);
+		expect(sentence.toEdits().map((edit) => [edit.start, edit.end])).toEqual([
+			[0, 7],
+			[10, 14]
+		]);
+	});
+
+	test('GIVEN the same pattern twice THEN needs two matches', () => {
+		expect(new ProximityRule(['kill', 'kill'], 2).matches(new Sentence('kill'))).toBe(false);
+		expect(new ProximityRule(['kill', 'kill'], 2).matches(new Sentence('kill kill'))).toBe(true);
+	});
+
+	test('GIVEN words matching the same characters THEN needs a match for each of them', () => {
+		expect(new ProximityRule(['kill', '**you**', 'you'], 3).matches(new Sentence('kill you'))).toBe(false);
+
+		const found = new ProximityRule(['kill', '**you**', 'you'], 3).find(new Sentence('kill you you'));
+		expect(found.map((match) => match.matches.map((word) => [word.start, word.end]))).toEqual([
+			[
+				[0, 4],
+				[5, 8],
+				[9, 12]
+			]
+		]);
+	});
+
+	test('GIVEN words that cannot be marked together THEN does not match', () => {
+		const sentence = new Sentence('kill abcd');
+
+		expect(new ProximityRule(['kill', '**ab**', '**cd**'], 3).find(sentence)).toEqual([]);
+		expect(sentence.toEdits()).toEqual([]);
+	});
+
+	test('GIVEN invalid patterns THEN throws', () => {
+		expect(() => new ProximityRule(['kill'], 1)).toThrowError('A proximity rule needs at least two patterns');
+		expect(() => new ProximityRule(['kill', '[you'], 1)).toThrowError(
+			expect.objectContaining({ code: ParseErrorCode.UnterminatedGroup, index: 0, word: 1 })
+		);
+	});
+});
@@ -1,4 +1,4 @@
-import { Boundary, Sentence, Word } from '../index';
+import { Boundary, MatchKind, Protection, RestrictionLevel, Sentence, Word } from '../index';
 
 describe('Sentence', () => {
 	test.each([
@@ -51,5 +51,382 @@ describe('Sentence', () => {
 			expect(word.matches(sentence)).toBe(false);
 			expect(sentence.toCensoredString({ original })).toBe('Hello world');
 		});
+
+		test('GIVEN several matching words THEN every word sees the text around the previous marks', () => {
+			const original = 'Pepe ate a banana';
+			const sentence = new Sentence(original);
+
+			expect(new Word('ate').matches(sentence)).toBe(true);
+			expect(new Word('banana').matches(sentence)).toBe(true);
+			expect(new Word('pepe').matches(sentence)).toBe(true);
+			expect(sentence.toCensoredString({ original })).toBe('**** *** a ******');
+		});
+	});
+
+	describe('toEdits', () => {
+		test('GIVEN a matching word THEN returns the edits over the original string', () => {
+			const sentence = new Sentence('Oh no! A b aaaa rrr!');
+			const word = new Word('bar');
+
+			expect(word.matches(sentence)).toBe(true);
+			expect(sentence.toEdits({ character: '-' })).toEqual([
+				{ start: 9, end: 10, byteStart: 9, byteEnd: 10, replacement: '-' },
+				{ start: 11, end: 15, byteStart: 11, byteEnd: 15, replacement: '----' },
+				{ start: 16, end: 19, byteStart: 16, byteEnd: 19, replacement: '---' }
+			]);
+		});
+
+		test('GIVEN a matching word with astral characters THEN returns UTF-16 and byte offsets', () => {
+			const sentence = new Sentence('𝕙ȩ𝕀𝓁ṓ world');
+			const word = new Word('hello');
+
+			expect(word.matches(sentence)).toBe(true);
+			expect(sentence.toEdits()).toEqual([{ start: 0, end: 8, byteStart: 0, byteEnd: 17, replacement: '*****' }]);
+		});
+
+		test('GIVEN a non-matching word THEN returns no edits', () => {
+			const sentence = new Sentence('Hello world');
+			const word = new Word('foo');
+
+			expect(word.matches(sentence)).toBe(false);
+			expect(sentence.toEdits()).toEqual([]);
+		});
+	});
+
+	describe('snapshot', () => {
+		test('GIVEN a snapshot THEN restores the marks to the saved state', () => {
+			const original = 'Pepe ate a banana';
+			const sentence = new Sentence(original);
+			const snapshot = sentence.snapshot();
+
+			expect(new Word('pepe').matches(sentence)).toBe(true);
+			expect(sentence.toCensoredString({ original })).toBe('**** ate a banana');
+
+			sentence.restore(snapshot);
+			expect(sentence.toCensoredString({ original })).toBe('Pepe ate a banana');
+			expect(new Word('banana').matches(sentence)).toBe(true);
+			expect(sentence.toCensoredString({ original })).toBe('Pepe ate a ******');
+		});
+
+		test('GIVEN resetMarks THEN removes all the marks', () => {
+			const original = 'Pepe ate a banana';
+			const sentence = new Sentence(original);
+
+			expect(new Word('pepe').matches(sentence)).toBe(true);
+			sentence.resetMarks();
+			expect(sentence.toCensoredString({ original })).toBe('Pepe ate a banana');
+			expect(new Word('pepe').matches(sentence)).toBe(true);
+		});
+
+		test('GIVEN a snapshot from another sentence THEN throws', () => {
+			const sentence = new Sentence('Pepe ate a banana');
+
+			expect(() => sentence.restore(new Sentence('Hello').snapshot())).toThrowError(
+				new Error('The snapshot must have been created by the same sentence')
+			);
+		});
+
+		test('GIVEN a snapshot of another sentence with the same length THEN throws', () => {
+			const sentence = new Sentence('aaaa');
+			const snapshot = new Sentence('bbbb').snapshot();
+
+			expect(() => sentence.restore(snapshot)).toThrowError(new Error('The snapshot must have been created by the same sentence'));
+			expect(() => new Sentence('<@1> bbbb', { protect: [Protection.Mention] }).restore(new Sentence('<@1> bbbb').snapshot())).toThrowError(
+				new Error('The snapshot must have been created by the same sentence')
+			);
+		});
+	});
+
+	describe('provenance', () => {
+		test('GIVEN normalized characters THEN returns how each one was normalized', () => {
+			const sentence = new Sentence('P\u0430y \u2469');
+
+			expect(sentence.provenance()).toEqual([
+				{ character: 'p', original: 'P', start: 0, end: 1, confusable: false, caseFolded: true },
+				{ character: 'a', original: '\u0430', start: 1, end: 2, confusable: true, caseFolded: false },
+				{ character: 'y', original: 'y', start: 2, end: 3, confusable: false, caseFolded: false },
+				{ character: ' ', original: ' ', start: 3, end: 4, confusable: false, caseFolded: false },
+				{ character: '1', original: '\u2469', start: 4, end: 5, confusable: true, caseFolded: false },
+				{ character: '0', original: '\u2469', start: 4, end: 5, confusable: true, caseFolded: false }
+			]);
+		});
+
+		test('GIVEN a match with confusables THEN flags the match', () => {
+			const sentence = new Sentence('p\u0430ypal paypal');
+
+			expect(new Word('paypal').find(sentence).map((match) => [match.start, match.confusable])).toEqual([
+				[0, true],
+				[7, false]
+			]);
+		});
+	});
+
+	describe('scripts', () => {
+		test('GIVEN a token mixing scripts THEN flags it', () => {
+			const sentence = new Sentence('Pay with \u0440aypal');
+
+			expect(sentence.scripts()).toEqual([
+				{ text: 'Pay', start: 0, end: 3, scripts: ['Latin'], mixed: false, restrictionLevel: RestrictionLevel.AsciiOnly },
+				{ text: 'with', start: 4, end: 8, scripts: ['Latin'], mixed: false, restrictionLevel: RestrictionLevel.AsciiOnly },
+				{
+					text: '\u0440aypal',
+					start: 9,
+					end: 15,
+					scripts: ['Cyrillic', 'Latin'],
+					mixed: true,
+					restrictionLevel: RestrictionLevel.MinimallyRestrictive
+				}
+			]);
+		});
+
+		test('GIVEN tokens with a single script THEN does not flag them', () => {
+			const tokens = new Sentence('caf\u00e9 \u6771\u4eac\u30bf\u30ef\u30fc').scripts();
+
+			expect(tokens.map((token) => [token.scripts, token.mixed, token.restrictionLevel])).toEqual([
+				[['Latin'], false, RestrictionLevel.SingleScript],
+				[['Han', 'Katakana'], false, RestrictionLevel.SingleScript]
+			]);
+		});
+	});
+
+	describe('protected ranges', () => {
+		const all = [Protection.Url, Protection.Mention, Protection.Channel, Protection.Role, Protection.Emoji];
+
+		test('GIVEN detectors THEN protects the detected ranges', () => {
+			const original = '<@123> <@!45> <@&6> <#7> <a:kekw:8> https://example.com/path.';
+			const sentence = new Sentence(original, { protect: all });
+
+			expect(sentence.protectedRanges().map((range) => [original.slice(range.start, range.end), range.kind])).toEqual([
+				['<@123>', Protection.Mention],
+				['<@!45>', Protection.Mention],
+				['<@&6>', Protection.Role],
+				['<#7>', Protection.Channel],
+				['<a:kekw:8>', Protection.Emoji],
+				['https://example.com/path', Protection.Url]
+			]);
+		});
+
+		test('GIVEN protected ranges THEN never marks inside them', () => {
+			const original = '<:ass:1> https://ass.com/ass ass';
+			const sentence = new Sentence(original, { protect: all });
+
+			expect(new Word('**ass**').matches(sentence)).toBe(true);
+			expect(sentence.toEdits()).toEqual([{ start: 29, end: 32, byteStart: 29, byteEnd: 32, replacement: '***' }]);
+
+			sentence.resetMarks();
+			expect(new Word('ass', { phonetic: true }).find(sentence).map((match) => match.start)).toEqual([29]);
+		});
+
+		test('GIVEN no detectors THEN does not protect anything', () => {
+			const sentence = new Sentence('<:ass:1>');
+
+			expect(sentence.protectedRanges()).toEqual([]);
+			expect(new Word('ass').matches(sentence)).toBe(true);
+		});
+
+		test('GIVEN custom ranges THEN protects them', () => {
+			const sentence = new Sentence('\u{1d559}ello badword', { ranges: [{ start: 7, end: 10 }] });
+
+			expect(sentence.protectedRanges()).toEqual([{ start: 7, end: 10, kind: Protection.Custom }]);
+			expect(new Word('badword').matches(sentence)).toBe(false);
+			expect(new Word('word').matches(sentence)).toBe(true);
+			expect(sentence.toEdits()).toEqual([{ start: 10, end: 14, byteStart: 12, byteEnd: 16, replacement: '****' }]);
+		});
+	});
+
+	describe('markdown', () => {
+		test.each([
+			['f**u**ck', 'fuck'],
+			['sh||i||t', 'shit'],
+			['b~~i~~tch', 'bitch'],
+			['**bold** text', '**bold** text']
+		])('GIVEN %j THEN ignores the formatting markers within words', (original, expected) => {
+			expect(new Sentence(original, { markdown: true }).toString()).toBe(expected);
+		});
+
+		test('GIVEN a match with formatting markers THEN censors the markers as well', () => {
+			const original = 'you sh||i||t';
+			const sentence = new Sentence(original, { markdown: true });
+
+			expect(new Word('shit', { phonetic: true }).find(sentence).map((match) => match.kind)).toEqual([MatchKind.Literal]);
+			expect(sentence.toEdits()).toEqual([{ start: 4, end: 12, byteStart: 4, byteEnd: 12, replacement: '********' }]);
+		});
+
+		test('GIVEN a match with formatting markers THEN toCensoredString censors the original text', () => {
+			const original = 'you f**u**ck';
+			const sentence = new Sentence(original, { markdown: true });
+
+			expect(new Word('fuck').matches(sentence)).toBe(true);
+			expect(sentence.toCensoredString({ original })).toBe('you ********');
+		});
+
+		test('GIVEN code and quote protection THEN skips code blocks and quotes', () => {
+			const original = '`shit` and\n> shit\n```\nshit\n``` shit';
+			const sentence = new Sentence(original, { protect: [Protection.Code, Protection.Quote] });
+
+			expect(sentence.protectedRanges().map((range) => [original.slice(range.start, range.end), range.kind])).toEqual([
+				['`shit`', Protection.Code],
+				['> shit', Protection.Quote],
+				['```\nshit\n```', Protection.Code]
+			]);
+			expect(new Word('shit').matches(sentence)).toBe(true);
+			expect(sentence.toEdits().map((edit) => original.slice(edit.start, edit.end))).toEqual(['shit']);
+			expect(sentence.toEdits()[0].start).toBe(original.length - 4);
+		});
+	});
+
+	describe('metrics', () => {
+		test('GIVEN a shouted message with mentions THEN returns its metrics', () => {
+			expect(new Sentence('HELLO <@123> <@!456> <@&7> @everyone!!!!').metrics).toEqual({
+				letters: 13,
+				uppercase: 5,
+				capsRatio: 5 / 13,
+				longestRepeat: 4,
+				floods: 1,
+				combiningMarks: 0,
+				zalgoDensity: 0,
+				longestMarkStack: 0,
+				emoji: 0,
+				emojiOnly: false,
+				mentions: 2,
+				roleMentions: 1,
+				massMentions: 1
+			});
+		});
+
+		test('GIVEN zalgo text THEN returns the density of combining marks', () => {
+			const metrics = new Sentence('h\u0337\u0322e\u0338l\u0336l\u0335o\u0334').metrics;
+
+			expect(metrics.combiningMarks).toBe(6);
+			expect(metrics.zalgoDensity).toBe(6 / 5);
+			expect(metrics.longestMarkStack).toBe(2);
+		});
+
+		test('GIVEN a message with only emoji THEN flags it', () => {
+			const metrics = new Sentence('\u{1f44d}\u{1f3fd} \u{1f1ea}\u{1f1f8} \u{1f468}\u200d\u{1f469}\u200d\u{1f467} <:kekw:1>').metrics;
+
+			expect(metrics.emoji).toBe(4);
+			expect(metrics.emojiOnly).toBe(true);
+			expect(new Sentence('nice \u{1f44d}').metrics.emojiOnly).toBe(false);
+		});
+	});
+
+	describe('zalgo', () => {
+		test('GIVEN stacked combining marks THEN folds them into their characters', () => {
+			const original = 'f\u0337\u0322\u031bu\u0338c\u0336k\u0335 you';
+			const sentence = new Sentence(original);
+
+			expect(sentence.toString()).toBe('fuck you');
+			expect(new Word('fuck').matches(sentence)).toBe(true);
+			expect(sentence.toEdits()).toEqual([{ start: 0, end: 10, byteStart: 0, byteEnd: 16, replacement: '**********' }]);
+		});
+
+		test('GIVEN a single accent THEN keeps it with its character', () => {
+			const sentence = () => new Sentence('cafe\u0301 ok');
+
+			expect(sentence().provenance()[3]).toMatchObject({ original: 'e\u0301', start: 3, end: 5 });
+			expect(new Word('(?l)caf\u00e9').matches(sentence())).toBe(true);
+			expect(new Word('(?l)cafe').matches(sentence())).toBe(false);
+		});
+
+		test('GIVEN combining marks that are part of words THEN keeps them', () => {
+			expect(new Sentence('\u0928\u092e\u0938\u094d\u0924\u0947').toString()).toBe('\u0928\u092e\u0938\u094d\u0924\u0947');
+		});
+
+		test('GIVEN a pattern with combining marks THEN folds them as well', () => {
+			expect(new Word('f\u0337uck').toString()).toBe('fuck');
+		});
+	});
+
+	describe('acrostic', () => {
+		test('GIVEN a word written down the start of the lines THEN matches and censors it', () => {
+			const sentence = Sentence.acrostic('Big news\nAll of you\nRead this');
+
+			expect(sentence.toString()).toBe('bar');
+			expect(new Word('bar').matches(sentence)).toBe(true);
+			expect(sentence.toEdits().map((edit) => [edit.start, edit.end])).toEqual([
+				[0, 1],
+				[9, 10],
+				[20, 21]
+			]);
+		});
+
+		test('GIVEN toCensoredString THEN censors the first characters of the lines', () => {
+			const original = 'ab\ncd';
+			const sentence = Sentence.acrostic(original);
+
+			expect(new Word('ac').matches(sentence)).toBe(true);
+			expect(sentence.toCensoredString({ original })).toBe('*b\n*d');
+		});
+
+		test('GIVEN lines without alphanumeric characters THEN skips them', () => {
+			expect(Sentence.acrostic(' - Pepe\n\n * apples\n...\nnope').toString()).toBe('pan');
+		});
+
+		test('GIVEN the last option THEN reads the last characters as a second word', () => {
+			const sentence = Sentence.acrostic('Pepe is here\nall ok\nno one', { last: true });
+
+			expect(sentence.toString()).toBe('pan eke');
+			expect(new Word('eke').matches(sentence)).toBe(true);
+			expect(sentence.toEdits().map((edit) => [edit.start, edit.end])).toEqual([
+				[11, 12],
+				[18, 19],
+				[25, 26]
+			]);
+		});
+	});
+
+	describe('statistics', () => {
+		test('GIVEN marked words THEN counts the marked characters, tokens, and patterns', () => {
+			const sentence = new Sentence('Pepe ate a banana');
+			new Word('pepe').matches(sentence);
+			new Word('banana').matches(sentence);
+			new Word('apple').matches(sentence);
+
+			expect(sentence.statistics()).toEqual({
+				characters: 14,
+				markedCharacters: 10,
+				markedRatio: 10 / 14,
+				tokens: 4,
+				markedTokens: 2,
+				patterns: [
+					{ pattern: 'pepe', maxDistance: 0, phonetic: false, reversed: false },
+					{ pattern: 'banana', maxDistance: 0, phonetic: false, reversed: false }
+				]
+			});
+		});
+
+		test('GIVEN words with the same pattern and different options THEN reports them separately', () => {
+			const sentence = new Sentence('fuk this phuk');
+			new Word('fuck', { maxDistance: 1 }).matches(sentence);
+			new Word('fuck', { phonetic: true }).matches(sentence);
+
+			expect(sentence.statistics().patterns).toEqual([
+				{ pattern: 'fuck', maxDistance: 1, phonetic: false, reversed: false },
+				{ pattern: 'fuck', maxDistance: 0, phonetic: true, reversed: false }
+			]);
+		});
+
+		test('GIVEN protected ranges THEN does not count them', () => {
+			const sentence = new Sentence('<@123> fuck fuck', { protect: [Protection.Mention] });
+			new Word('fuck').matches(sentence);
+
+			expect(sentence.statistics()).toEqual(expect.objectContaining({ characters: 8, markedRatio: 1, tokens: 2 }));
+		});
+
+		test('GIVEN restored or reset marks THEN restores the patterns as well', () => {
+			const sentence = new Sentence('Pepe ate a banana');
+			new Word('pepe').matches(sentence);
+
+			const snapshot = sentence.snapshot();
+			new Word('ate').matches(sentence);
+			expect(sentence.statistics().patterns.map((pattern) => pattern.pattern)).toEqual(['pepe', 'ate']);
+
+			sentence.restore(snapshot);
+			expect(sentence.statistics().patterns.map((pattern) => pattern.pattern)).toEqual(['pepe']);
+
+			sentence.resetMarks();
+			expect(sentence.statistics()).toEqual(expect.objectContaining({ markedCharacters: 0, markedRatio: 0, patterns: [] }));
+		});
 	});
 });
@@ -0,0 +1,60 @@
+import { StreamScanner, WordList } from '../index';
+
+describe('StreamScanner', () => {
+	test('GIVEN words split across chunks THEN matches them with stream offsets', () => {
+		const scanner = new StreamScanner(new WordList(['pepe', 'banana']));
+		const chunks = ['Pepe ate a ban', 'ana and 𝕡epe, then b an', 'ana pe', 'pe'];
+		const text = chunks.join('');
+
+		const matches = chunks.flatMap((chunk) => scanner.push(chunk));
+		matches.push(...scanner.finish());
+
+		expect(matches.map((match) => [match.word, text.slice(match.start, match.end)])).toEqual([
+			[0, 'Pepe'],
+			[1, 'banana'],
+			[0, '𝕡epe'],
+			[1, 'b anana'],
+			[0, 'pepe']
+		]);
+	});
+
+	test('GIVEN a long stream THEN reports every match once', () => {
+		const scanner = new StreamScanner(new WordList(['pepe']), { overlap: 4 });
+		const chunks = Array.from({ length: 1000 }, (_, index) => (index % 100 === 0 ? 'pepe ' : 'lorem '));
+
+		const matches = chunks.flatMap((chunk) => scanner.push(chunk));
+		matches.push(...scanner.finish());
+
+		expect(matches).toHaveLength(10);
+		expect(matches[1]).toEqual({ word: 0, start: 99 * 6 + 5, end: 99 * 6 + 9 });
+	});
+
+	test('GIVEN a stream without non-word characters THEN matches before it ends', () => {
+		const scanner = new StreamScanner(new WordList(['**pepe**']));
+		const chunks = ['x'.repeat(50), 'pe', 'pe', ...Array.from({ length: 50 }, () => 'y')];
+
+		expect(chunks.flatMap((chunk) => scanner.push(chunk))).toEqual([{ word: 0, start: 50, end: 54 }]);
+		expect(scanner.finish()).toEqual([]);
+	});
+
+	test('GIVEN a zalgo word split across chunks THEN matches it without overlap', () => {
+		const scanner = new StreamScanner(new WordList(['fuck']), { overlap: 0 });
+		const chunks = ['go f\u0337\u0322', '\u031bu\u0338c', '\u0336k\u0335', '\u0334 you'];
+		const text = chunks.join('');
+
+		const matches = chunks.flatMap((chunk) => scanner.push(chunk));
+		matches.push(...scanner.finish());
+
+		expect(matches.map((match) => text.slice(match.start, match.end))).toEqual(['f\u0337\u0322\u031bu\u0338c\u0336k\u0335\u0334']);
+	});
+
+	test('GIVEN a long word cut by the scanner THEN does not read the cut as a word boundary', () => {
+		const scanner = new StreamScanner(new WordList(['pepe', 'pepe**']), { overlap: 4 });
+		const chunks = ['a'.repeat(20), 'pe', 'pe', ' '];
+
+		const matches = chunks.flatMap((chunk) => scanner.push(chunk));
+		matches.push(...scanner.finish());
+
+		expect(matches).toEqual([]);
+	});
+});
@@ -1,4 +1,4 @@
-import { Sentence, Word } from '../index';
+import { AbandonReason, MatchKind, ParseErrorCode, Protection, Sentence, TraceStepKind, Word, WordPartKind } from '../index';
 
 describe('Word', () => {
 	test('GIVEN full bound word THEN matches full words only', () => {
@@ -71,6 +71,332 @@ describe('Word', () => {
 		expect(sentence.toCensoredString({ original, character: '-' })).toBe('Oh no! A - ---- ---!');
 	});
 
+	test('GIVEN left unbound word within a sentence THEN matches the suffix', () => {
+		const word = new Word('**bar');
+		const original = 'a rebar b';
+		const sentence = new Sentence(original);
+
+		expect(word.matches(sentence)).toBe(true);
+		expect(sentence.toCensoredString({ original })).toBe('a ***** b');
+	});
+
+	test('GIVEN a single character word THEN matches every occurrence', () => {
+		const word = new Word('a');
+		const original = 'a b a';
+		const sentence = new Sentence(original);
+
+		expect(word.matches(sentence)).toBe(true);
+		expect(sentence.toCensoredString({ original })).toBe('* b *');
+	});
+
+	test('GIVEN find THEN returns the position of every match', () => {
+		const word = new Word('pepe');
+		const sentence = new Sentence('Pepe ate pepe');
+
+		expect(word.find(sentence).map((match) => [match.start, match.end])).toEqual([
+			[0, 4],
+			[9, 13]
+		]);
+	});
+
+	describe('fuzzy', () => {
+		test('GIVEN a max distance THEN matches words within the distance', () => {
+			const word = new Word('fuck', { maxDistance: 1 });
+
+			expect(word.maxDistance).toBe(1);
+			expect(word.matches(new Sentence('fcuk'))).toBe(true);
+			expect(word.matches(new Sentence('fuk'))).toBe(true);
+			expect(word.matches(new Sentence('fuuck'))).toBe(true);
+			expect(word.matches(new Sentence('fack'))).toBe(true);
+			expect(word.matches(new Sentence('phuck'))).toBe(false);
+			expect(word.matches(new Sentence('fucking'))).toBe(false);
+		});
+
+		test('GIVEN find THEN returns the distance of every match', () => {
+			const word = new Word('fuck', { maxDistance: 2 });
+			const sentence = new Sentence('fuck this fcuk');
+
+			expect(word.find(sentence).map((match) => [match.start, match.end, match.distance, match.kind])).toEqual([
+				[0, 4, 0, MatchKind.Literal],
+				[10, 14, 1, MatchKind.Fuzzy]
+			]);
+		});
+
+		test('GIVEN unbound boundaries THEN matches within words', () => {
+			expect(new Word('fuck**', { maxDistance: 1 }).matches(new Sentence('fcuking'))).toBe(true);
+			expect(new Word('**fuck', { maxDistance: 1 }).matches(new Sentence('motherfuker'))).toBe(false);
+			expect(new Word('**fuck', { maxDistance: 1 }).matches(new Sentence('motherfuk'))).toBe(true);
+			expect(new Word('**fuck**', { maxDistance: 1 }).matches(new Sentence('xfcukx'))).toBe(true);
+		});
+
+		test('GIVEN a distance as large as the word THEN does not match every word', () => {
+			const word = new Word('ab', { maxDistance: 2 });
+
+			expect(word.matches(new Sentence('xy'))).toBe(false);
+			expect(word.matches(new Sentence('ay'))).toBe(false);
+			expect(word.matches(new Sentence('ab'))).toBe(true);
+		});
+
+		test('GIVEN a short word THEN allows one edit every three characters', () => {
+			expect(new Word('at', { maxDistance: 1 }).matches(new Sentence('a an'))).toBe(false);
+			expect(new Word('cat', { maxDistance: 2 }).matches(new Sentence('c a'))).toBe(false);
+			expect(new Word('cat', { maxDistance: 2 }).matches(new Sentence('ca'))).toBe(true);
+			expect(new Word('cat', { maxDistance: 2 }).matches(new Sentence('ct'))).toBe(true);
+		});
+	});
+
+	describe('phonetic', () => {
+		test('GIVEN a phonetic word THEN matches words that sound alike', () => {
+			const word = new Word('fuck', { phonetic: true });
+
+			expect(word.phonetic).toBe(true);
+			expect(word.matches(new Sentence('phuk'))).toBe(true);
+			expect(word.matches(new Sentence('fuk'))).toBe(true);
+			expect(word.matches(new Sentence('fork'))).toBe(false);
+			expect(new Word('cunt', { phonetic: true }).matches(new Sentence('kunt'))).toBe(true);
+			expect(new Word('shit', { phonetic: true }).matches(new Sentence('sheeit'))).toBe(true);
+			expect(new Word('shit', { phonetic: true }).matches(new Sentence('sit'))).toBe(false);
+		});
+
+		test('GIVEN words with other vowels THEN does not match them', () => {
+			const word = new Word('fuck', { phonetic: true });
+
+			expect(word.matches(new Sentence('fake'))).toBe(false);
+			expect(word.matches(new Sentence('fog'))).toBe(false);
+			expect(new Word('cunt', { phonetic: true }).matches(new Sentence('count'))).toBe(false);
+			expect(new Word('cunt', { phonetic: true }).matches(new Sentence('kind'))).toBe(false);
+		});
+
+		test('GIVEN find THEN reports phonetic matches', () => {
+			const word = new Word('fuck', { phonetic: true });
+			const sentence = new Sentence('fuck this phuk');
+
+			expect(word.find(sentence).map((match) => [match.start, match.end, match.kind])).toEqual([
+				[0, 4, MatchKind.Literal],
+				[10, 14, MatchKind.Phonetic]
+			]);
+		});
+
+		test('GIVEN unbound boundaries THEN matches the start or the end of words', () => {
+			expect(new Word('fuck**', { phonetic: true }).matches(new Sentence('phukking'))).toBe(true);
+			expect(new Word('**fuck', { phonetic: true }).matches(new Sentence('motherphuk'))).toBe(true);
+			expect(new Word('**fuck', { phonetic: true }).matches(new Sentence('phukking'))).toBe(false);
+		});
+	});
+
+	describe('reversed', () => {
+		test('GIVEN a reversed word THEN matches words written backwards', () => {
+			const word = new Word('banana', { reversed: true });
+
+			expect(word.reversed).toBe(true);
+			expect(word.matches(new Sentence('ananab'))).toBe(true);
+			expect(word.matches(new Sentence('ananabs'))).toBe(false);
+			expect(new Word('banana').matches(new Sentence('ananab'))).toBe(false);
+		});
+
+		test('GIVEN find THEN reports reversed matches on the original characters', () => {
+			const original = 'banana \u202eananab';
+			const sentence = new Sentence(original);
+
+			expect(new Word('banana', { reversed: true }).find(sentence).map((match) => [match.start, match.end, match.kind])).toEqual([
+				[0, 6, MatchKind.Literal],
+				[8, 14, MatchKind.Reversed]
+			]);
+			expect(sentence.toEdits().map((edit) => original.slice(edit.start, edit.end))).toEqual(['banana', 'ananab']);
+		});
+
+		test('GIVEN unbound boundaries THEN matches the reversed start or end of words', () => {
+			expect(new Word('ban**', { reversed: true }).find(new Sentence('xxnab')).map((match) => [match.start, match.end])).toEqual([
+				[2, 5]
+			]);
+			expect(new Word('**nana', { reversed: true }).find(new Sentence('ananabxx')).map((match) => [match.start, match.end])).toEqual([
+				[0, 4]
+			]);
+		});
+	});
+
+	describe('phrases', () => {
+		test('GIVEN a phrase THEN matches consecutive tokens separated by any non-word characters', () => {
+			const word = new Word('kill yourself');
+
+			expect(word.toString()).toBe('kill yourself');
+			expect(word.matches(new Sentence('pls kill...   yourself now'))).toBe(true);
+			expect(word.matches(new Sentence('kill yourselfie'))).toBe(false);
+			expect(word.matches(new Sentence('kill it yourself'))).toBe(false);
+		});
+
+		test('GIVEN a phrase THEN marks all its tokens', () => {
+			const sentence = new Sentence('kill kill yourself');
+
+			expect(new Word('kill yourself').find(sentence).map((match) => [match.start, match.end])).toEqual([[5, 18]]);
+			expect(sentence.toEdits().map((edit) => [edit.start, edit.end])).toEqual([
+				[5, 9],
+				[10, 18]
+			]);
+		});
+
+		test('GIVEN a marked token between the words of a phrase THEN does not match', () => {
+			const sentence = new Sentence('kill bad yourself');
+
+			expect(new Word('bad').matches(sentence)).toBe(true);
+			expect(new Word('kill yourself').find(sentence)).toEqual([]);
+		});
+
+		test('GIVEN a protected range between the words of a phrase THEN does not match', () => {
+			const sentence = new Sentence('kill <@123> yourself', { protect: [Protection.Mention] });
+
+			expect(new Word('kill yourself').find(sentence)).toEqual([]);
+			expect(sentence.toEdits()).toEqual([]);
+		});
+
+		test('GIVEN boundaries within a phrase THEN applies them to their own token', () => {
+			expect(new Word('kill yourself**').matches(new Sentence('go kill yourselfie'))).toBe(true);
+			expect(new Word('**ass hole').matches(new Sentence('a dumbass hole'))).toBe(true);
+			expect(new Word('**ass hole').matches(new Sentence('a dumbass holes'))).toBe(false);
+		});
+
+		test('GIVEN options THEN applies them to every word of the phrase', () => {
+			const found = new Word('kill yourself', { maxDistance: 1 }).find(new Sentence('kil yourslef'));
+
+			expect(found.map((match) => [match.start, match.end, match.distance, match.kind])).toEqual([[0, 12, 2, MatchKind.Fuzzy]]);
+		});
+	});
+
+	describe('flags', () => {
+		test('GIVEN the case-sensitive flag THEN only matches the same case', () => {
+			const word = new Word('(?c)NASA');
+			const sentence = new Sentence('nasa and NASA');
+
+			expect(word.caseSensitive).toBe(true);
+			expect(word.find(sentence).map((match) => [match.start, match.end])).toEqual([[9, 13]]);
+			expect(new Word('NASA').matches(new Sentence('nasa'))).toBe(true);
+		});
+
+		test('GIVEN the literal flag THEN does not match confusables', () => {
+			const sentence = new Sentence('p\u0430ypal paypal');
+
+			expect(new Word('(?l)paypal').find(sentence).map((match) => [match.start, match.end])).toEqual([[7, 13]]);
+			expect(new Word('paypal').matches(new Sentence('p\u0430ypal'))).toBe(true);
+		});
+
+		test('GIVEN both flags THEN matches the original characters', () => {
+			const word = new Word('(?lc)PayPal');
+
+			expect(word.toString()).toBe('(?cl)PayPal');
+			expect(word.matches(new Sentence('P\u0430yPal paypal'))).toBe(false);
+			expect(word.matches(new Sentence('PayPal'))).toBe(true);
+		});
+
+		test('GIVEN marks and protected ranges THEN keeps them for flagged words', () => {
+			const sentence = new Sentence('<@1> NASA NASA', { protect: [Protection.Mention] });
+
+			expect(new Word('nasa').find(sentence)).toHaveLength(2);
+			expect(new Word('(?c)NASA').matches(sentence)).toBe(false);
+		});
+	});
+
+	describe('parts', () => {
+		test('GIVEN a pattern THEN returns its parts', () => {
+			const word = new Word('**b[a4]*r\\*');
+
+			expect(word.parts).toEqual([
+				{ kind: WordPartKind.Single, char: 'b' },
+				{ kind: WordPartKind.Group, chars: ['a', '4'] },
+				{ kind: WordPartKind.SingleWildcard },
+				{ kind: WordPartKind.Single, char: 'r' },
+				{ kind: WordPartKind.Single, char: '*' }
+			]);
+		});
+
+		test('GIVEN a list of parts THEN builds the word', () => {
+			const word = Word.fromParts(
+				[
+					{ kind: WordPartKind.Single, char: 'B' },
+					{ kind: WordPartKind.Group, chars: ['a', '4'] },
+					{ kind: WordPartKind.Single, char: 'r' }
+				],
+				{ boundRight: false }
+			);
+
+			expect(word.toString()).toBe('b[a4]r**');
+			expect(word.boundLeft).toBe(true);
+			expect(word.boundRight).toBe(false);
+			expect(word.matches(new Sentence('b4rbie'))).toBe(true);
+		});
+
+		test('GIVEN the parts of a word THEN builds the same word', () => {
+			const word = new Word('**b[a4]*r\\*');
+			const copy = Word.fromParts(word.parts, { boundLeft: word.boundLeft, boundRight: word.boundRight });
+
+			expect(copy.toString()).toBe(word.toString());
+		});
+
+		test('GIVEN a character with combining marks THEN reads it like a pattern', () => {
+			const parts = [
+				{ kind: WordPartKind.Single, char: 'e\u0301' },
+				{ kind: WordPartKind.Group, chars: ['a\u0337\u0322', 'b'] }
+			];
+
+			expect(Word.fromParts(parts).toString()).toBe(new Word('e\u0301[a\u0337\u0322b]').toString());
+			expect(Word.fromParts(parts).toString()).toBe('e[ab]');
+		});
+
+		test('GIVEN a phrase THEN throws when reading its parts', () => {
+			expect(() => new Word('kill yourself').parts).toThrowError(new Error('The parts of a phrase cannot be read'));
+		});
+
+		test('GIVEN a single part with several characters THEN throws', () => {
+			expect(() => Word.fromParts([{ kind: WordPartKind.Single, char: 'ab' }])).toThrowError(
+				new Error('A single part must have exactly one character')
+			);
+		});
+	});
+
+	describe('explain', () => {
+		test('GIVEN a matching word THEN returns the trace of the match', () => {
+			const trace = new Word('**ass**').explain('Hello, class!');
+
+			expect(trace.normalized).toBe('hello, class!');
+			expect(trace.pattern).toBe('**ass**');
+			expect(trace.matched).toBe(true);
+			expect(trace.spans).toHaveLength(1);
+			expect(trace.spans[0].candidates).toEqual([
+				{
+					start: 9,
+					matchStart: 9,
+					matchEnd: 12,
+					distance: 0,
+					kind: MatchKind.Literal,
+					matched: true,
+					steps: [
+						{ index: 9, character: 'a', kind: TraceStepKind.Matched, part: 0, pattern: 'a' },
+						{ index: 10, character: 's', kind: TraceStepKind.Matched, part: 1, pattern: 's' },
+						{ index: 11, character: 's', kind: TraceStepKind.Matched, part: 2, pattern: 's' },
+						{ index: 12, character: '!', kind: TraceStepKind.Skipped }
+					]
+				}
+			]);
+		});
+
+		test('GIVEN a non-matching word THEN returns the reason of every abandoned candidate', () => {
+			const trace = new Word('bar').explain('baz');
+
+			expect(trace.matched).toBe(false);
+			expect(trace.spans[0].candidates).toEqual([
+				{
+					start: 0,
+					matched: false,
+					reason: AbandonReason.Mismatch,
+					steps: [
+						{ index: 0, character: 'b', kind: TraceStepKind.Matched, part: 0, pattern: 'b' },
+						{ index: 1, character: 'a', kind: TraceStepKind.Matched, part: 1, pattern: 'a' },
+						{ index: 2, character: 'z', kind: TraceStepKind.Mismatched }
+					]
+				}
+			]);
+		});
+	});
+
 	describe('edge cases', () => {
 		test('GIVEN an empty group THEN gets omitted', () => {
 			const word = new Word('foo[]');
@@ -90,6 +416,17 @@ describe('Word', () => {
 			expect(() => new Word('bar\\')).toThrowError(new Error('Escape character cannot be at the end of the word'));
 		});
 
+		test('GIVEN escaped characters THEN writes them back escaped', () => {
+			const word = new Word('a\\\\b\\*[\\]x]');
+
+			expect(word.toString()).toBe('a\\\\b\\*[\\]x]');
+			expect(new Word(word.toString()).toString()).toBe(word.toString());
+		});
+
+		test('GIVEN an escaped whitespace THEN throws', () => {
+			expect(() => new Word('a\\ b')).toThrowError(new Error('Whitespace cannot be escaped outside a group'));
+		});
+
 		test('GIVEN an empty word THEN throws', () => {
 			expect(() => new Word('')).toThrowError(new Error('The word cannot be empty'));
 		});
@@ -101,5 +438,26 @@ describe('Word', () => {
 		test('GIVEN an empty word with unbound start and end THEN throws', () => {
 			expect(() => new Word('****')).toThrowError(new Error('Wildcards cannot be the only character in the word'));
 		});
+
+		test.each([
+			['[bar', ParseErrorCode.UnterminatedGroup, 0],
+			['𝕙[bar', ParseErrorCode.UnterminatedGroup, 2],
+			['bar\\', ParseErrorCode.TrailingEscape, 3],
+			['', ParseErrorCode.EmptyPattern, 0],
+			['**', ParseErrorCode.WildcardOnly, 0],
+			['****', ParseErrorCode.WildcardOnly, 2],
+			['(?cx)bar', ParseErrorCode.UnknownFlag, 3],
+			['(?cbar', ParseErrorCode.UnterminatedFlags, 0],
+			['(?)bar', ParseErrorCode.EmptyFlags, 0],
+			['a\\ b', ParseErrorCode.EscapedWhitespace, 1]
+		])('GIVEN the invalid pattern %j THEN throws with code %s and index %d', (pattern, code, index) => {
+			expect(() => new Word(pattern)).toThrowError(expect.objectContaining({ code, index }));
+		});
+
+		test('GIVEN an invalid part THEN throws with the index of the part', () => {
+			expect(() => Word.fromParts([{ kind: WordPartKind.Single, char: 'a' }, { kind: WordPartKind.Group, chars: ['ab'] }])).toThrowError(
+				expect.objectContaining({ code: ParseErrorCode.InvalidPart, index: 1 })
+			);
+		});
 	});
 });
@@ -0,0 +1,70 @@
+import { ParseErrorCode, Sentence, WordList } from '../index';
+
+describe('WordList', () => {
+	test('GIVEN a list of words THEN matches all of them', () => {
+		const list = new WordList(['pepe', 'banana']);
+		const original = 'Pepe ate a banana';
+		const sentence = new Sentence(original);
+
+		expect(list.length).toBe(2);
+		expect(list.matches(sentence)).toBe(true);
+		expect(sentence.toCensoredString({ original })).toBe('**** ate a ******');
+	});
+
+	test('GIVEN an invalid word THEN throws', () => {
+		expect(() => new WordList(['foo', '[bar'])).toThrowError(new Error('Unterminated character group'));
+	});
+
+	test('GIVEN an invalid word THEN throws with the index of the word', () => {
+		expect(() => new WordList(['foo', 'x[bar'])).toThrowError(
+			expect.objectContaining({ code: ParseErrorCode.UnterminatedGroup, index: 1, word: 1 })
+		);
+	});
+
+	describe('matchBatch', () => {
+		test('GIVEN a batch of messages THEN resolves with the results in order', async () => {
+			const list = new WordList(['pepe', 'banana']);
+
+			await expect(list.matchBatch(['Pepe ate a banana', 'Hello world', 'a banana'])).resolves.toEqual([
+				{
+					matched: true,
+					words: [0, 1],
+					edits: [
+						{ start: 0, end: 4, byteStart: 0, byteEnd: 4, replacement: '****' },
+						{ start: 11, end: 17, byteStart: 11, byteEnd: 17, replacement: '******' }
+					]
+				},
+				{ matched: false, words: [], edits: [] },
+				{ matched: true, words: [1], edits: [{ start: 2, end: 8, byteStart: 2, byteEnd: 8, replacement: '******' }] }
+			]);
+		});
+
+		test('GIVEN an aborted signal THEN rejects', async () => {
+			const list = new WordList(['pepe']);
+
+			await expect(list.matchBatch(['Pepe'], AbortSignal.abort())).rejects.toThrowError('AbortError');
+		});
+
+		test('GIVEN a signal aborted while matching THEN rejects', async () => {
+			const list = new WordList(['pepe', '**ass**']);
+			const controller = new AbortController();
+
+			const promise = list.matchBatch(Array.from({ length: 100_000 }, () => 'Pepe ate a banana in class'), controller.signal);
+			controller.abort();
+
+			await expect(promise).rejects.toThrowError('AbortError');
+		});
+	});
+
+	describe('matchParallel', () => {
+		test('GIVEN a batch of messages THEN returns the results in order', () => {
+			const list = new WordList(['pepe', 'banana']);
+			const messages = Array.from({ length: 100 }, (_, index) => (index % 2 === 0 ? 'Pepe ate a banana' : 'Hello world'));
+
+			const results = list.matchParallel(messages);
+			expect(results).toHaveLength(100);
+			expect(results.map((result) => result.matched)).toEqual(messages.map((_, index) => index % 2 === 0));
+			expect(results[0].words).toEqual([0, 1]);
+		});
+	});
+});